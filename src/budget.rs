@@ -0,0 +1,380 @@
+// src/budget.rs
+
+//! Client-side budget and quota enforcement.
+//!
+//! A [`Budget`] attached to a [`Client`](crate::client::Client) refuses requests with
+//! [`Error::BudgetExceeded`] before they reach the network once a key has used up its
+//! tokens per day, dollars per day or requests per minute. Usage is kept in a
+//! [`BudgetStore`]; [`InMemoryBudgetStore`] is the default, and the trait is object-safe
+//! so shared backends (e.g. Redis) can be plugged in.
+
+use crate::client::{Error, Result};
+use crate::cost::{PricingTable, UsageCost};
+use crate::models::responses::Metadata;
+use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The budget key used when no key can be derived from a request.
+pub const DEFAULT_BUDGET_KEY: &str = "default";
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+//=======================================================================================
+// Limits and Usage
+//=======================================================================================
+
+/// The kind of limit that was exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BudgetLimitKind {
+    TokensPerDay,
+    DollarsPerDay,
+    RequestsPerMinute,
+}
+
+impl fmt::Display for BudgetLimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetLimitKind::TokensPerDay => write!(f, "tokens/day"),
+            BudgetLimitKind::DollarsPerDay => write!(f, "dollars/day"),
+            BudgetLimitKind::RequestsPerMinute => write!(f, "requests/minute"),
+        }
+    }
+}
+
+/// A limit that a key has reached.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BudgetViolation {
+    pub kind: BudgetLimitKind,
+    pub used: f64,
+    pub limit: f64,
+}
+
+/// The limits applied to a budget key. Unset limits are not enforced.
+///
+/// Days are UTC calendar days and minutes are fixed clock minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BudgetLimits {
+    /// The maximum number of input plus output tokens per day.
+    pub tokens_per_day: Option<u64>,
+    /// The maximum spend per day, in USD.
+    pub dollars_per_day: Option<f64>,
+    /// The maximum number of requests per minute.
+    pub requests_per_minute: Option<u64>,
+}
+
+impl BudgetLimits {
+    /// Creates a set of limits with nothing enforced.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tokens_per_day(mut self, limit: u64) -> Self {
+        self.tokens_per_day = Some(limit);
+        self
+    }
+
+    pub fn dollars_per_day(mut self, limit: f64) -> Self {
+        self.dollars_per_day = Some(limit);
+        self
+    }
+
+    pub fn requests_per_minute(mut self, limit: u64) -> Self {
+        self.requests_per_minute = Some(limit);
+        self
+    }
+
+    /// Returns the first limit that `usage` has reached, if any.
+    pub fn check(&self, usage: &BudgetUsage) -> Option<BudgetViolation> {
+        let violation = |kind, used: f64, limit: f64| Some(BudgetViolation { kind, used, limit });
+        if let Some(limit) = self.requests_per_minute
+            && usage.requests_this_minute >= limit
+        {
+            return violation(BudgetLimitKind::RequestsPerMinute, usage.requests_this_minute as f64, limit as f64);
+        }
+        if let Some(limit) = self.tokens_per_day
+            && usage.tokens_today >= limit
+        {
+            return violation(BudgetLimitKind::TokensPerDay, usage.tokens_today as f64, limit as f64);
+        }
+        if let Some(limit) = self.dollars_per_day
+            && usage.dollars_today >= limit
+        {
+            return violation(BudgetLimitKind::DollarsPerDay, usage.dollars_today, limit);
+        }
+        None
+    }
+}
+
+/// The usage of a budget key within the current windows.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BudgetUsage {
+    /// Tokens used in the current day.
+    pub tokens_today: u64,
+    /// Spend in the current day, in USD.
+    pub dollars_today: f64,
+    /// Requests started in the current minute.
+    pub requests_this_minute: u64,
+}
+
+/// The fixed windows a timestamp falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetWindows {
+    /// Days since the Unix epoch.
+    pub day: u64,
+    /// Minutes since the Unix epoch.
+    pub minute: u64,
+}
+
+impl BudgetWindows {
+    /// Returns the windows for the given point in time.
+    pub fn at(time: SystemTime) -> Self {
+        let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        Self { day: secs / SECONDS_PER_DAY, minute: secs / SECONDS_PER_MINUTE }
+    }
+
+    /// Returns the windows for the current time.
+    pub fn now() -> Self {
+        Self::at(SystemTime::now())
+    }
+}
+
+//=======================================================================================
+// Storage
+//=======================================================================================
+
+/// A backend that stores budget usage per key.
+pub trait BudgetStore: fmt::Debug + Send + Sync {
+    /// Returns the usage of `key` within `windows`.
+    fn usage<'a>(&'a self, key: &'a str, windows: BudgetWindows) -> BoxFuture<'a, Result<BudgetUsage>>;
+
+    /// Checks `key` against `limits` and, if none has been reached, counts a new request.
+    /// Returns the limit that was reached otherwise.
+    ///
+    /// The check and the increment must be atomic, so that concurrent callers cannot all
+    /// pass a limit that only one of them should.
+    fn try_record_request<'a>(
+        &'a self,
+        key: &'a str,
+        windows: BudgetWindows,
+        limits: &'a BudgetLimits,
+    ) -> BoxFuture<'a, Result<Option<BudgetViolation>>>;
+
+    /// Records the tokens and spend of a finished request for `key`.
+    fn record_usage<'a>(
+        &'a self,
+        key: &'a str,
+        windows: BudgetWindows,
+        tokens: u64,
+        dollars: f64,
+    ) -> BoxFuture<'a, Result<()>>;
+}
+
+#[derive(Debug, Default)]
+struct KeyUsage {
+    day: u64,
+    minute: u64,
+    usage: BudgetUsage,
+}
+
+impl KeyUsage {
+    fn roll(&mut self, windows: BudgetWindows) {
+        if self.day != windows.day {
+            self.day = windows.day;
+            self.usage.tokens_today = 0;
+            self.usage.dollars_today = 0.0;
+        }
+        if self.minute != windows.minute {
+            self.minute = windows.minute;
+            self.usage.requests_this_minute = 0;
+        }
+    }
+}
+
+/// A process-local [`BudgetStore`].
+#[derive(Debug, Default)]
+pub struct InMemoryBudgetStore {
+    keys: Mutex<HashMap<String, KeyUsage>>,
+}
+
+impl InMemoryBudgetStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_key<T>(&self, key: &str, windows: BudgetWindows, f: impl FnOnce(&mut BudgetUsage) -> T) -> T {
+        let mut keys = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = keys.entry(key.to_string()).or_default();
+        entry.roll(windows);
+        f(&mut entry.usage)
+    }
+}
+
+impl BudgetStore for InMemoryBudgetStore {
+    fn usage<'a>(&'a self, key: &'a str, windows: BudgetWindows) -> BoxFuture<'a, Result<BudgetUsage>> {
+        let usage = self.with_key(key, windows, |usage| *usage);
+        Box::pin(async move { Ok(usage) })
+    }
+
+    fn try_record_request<'a>(
+        &'a self,
+        key: &'a str,
+        windows: BudgetWindows,
+        limits: &'a BudgetLimits,
+    ) -> BoxFuture<'a, Result<Option<BudgetViolation>>> {
+        let violation = self.with_key(key, windows, |usage| {
+            let violation = limits.check(usage);
+            if violation.is_none() {
+                usage.requests_this_minute += 1;
+            }
+            violation
+        });
+        Box::pin(async move { Ok(violation) })
+    }
+
+    fn record_usage<'a>(
+        &'a self,
+        key: &'a str,
+        windows: BudgetWindows,
+        tokens: u64,
+        dollars: f64,
+    ) -> BoxFuture<'a, Result<()>> {
+        self.with_key(key, windows, |usage| {
+            usage.tokens_today += tokens;
+            usage.dollars_today += dollars;
+        });
+        Box::pin(async { Ok(()) })
+    }
+}
+
+//=======================================================================================
+// Enforcement
+//=======================================================================================
+
+type RecordErrorHandler = Arc<dyn Fn(&str, &Error) + Send + Sync>;
+
+/// Enforces [`BudgetLimits`] per key before requests are sent.
+///
+/// Every key uses the default limits unless it was given its own with
+/// [`Budget::with_key_limits`]. The key of a request is read from its `metadata` under
+/// [`Budget::with_key_metadata`], falling back to [`DEFAULT_BUDGET_KEY`].
+#[derive(Clone)]
+pub struct Budget {
+    default_limits: BudgetLimits,
+    key_limits: HashMap<String, BudgetLimits>,
+    key_metadata: Option<String>,
+    pricing: PricingTable,
+    store: Arc<dyn BudgetStore>,
+    on_record_error: Option<RecordErrorHandler>,
+}
+
+impl Budget {
+    /// Creates a budget with the given default limits, an in-memory store and the
+    /// built-in pricing table.
+    pub fn new(default_limits: BudgetLimits) -> Self {
+        Self {
+            default_limits,
+            key_limits: HashMap::new(),
+            key_metadata: None,
+            pricing: PricingTable::builtin(),
+            store: Arc::new(InMemoryBudgetStore::new()),
+            on_record_error: None,
+        }
+    }
+
+    /// Sets the limits for a specific key.
+    pub fn with_key_limits(mut self, key: impl Into<String>, limits: BudgetLimits) -> Self {
+        self.key_limits.insert(key.into(), limits);
+        self
+    }
+
+    /// Sets the request metadata key whose value is used as the budget key.
+    pub fn with_key_metadata(mut self, metadata_key: impl Into<String>) -> Self {
+        self.key_metadata = Some(metadata_key.into());
+        self
+    }
+
+    /// Sets the pricing table used to enforce `dollars_per_day`.
+    pub fn with_pricing(mut self, pricing: PricingTable) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Sets the backend that stores usage.
+    pub fn with_store(mut self, store: Arc<dyn BudgetStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Calls `handler` with the key and error when the usage of a finished request cannot
+    /// be recorded by the client. Such failures are otherwise ignored, since the request
+    /// itself succeeded.
+    pub fn with_record_error_handler(mut self, handler: impl Fn(&str, &Error) + Send + Sync + 'static) -> Self {
+        self.on_record_error = Some(Arc::new(handler));
+        self
+    }
+
+    /// Derives the budget key for a request from its metadata.
+    pub fn key_for(&self, metadata: Option<&Metadata>) -> String {
+        self.key_metadata
+            .as_ref()
+            .and_then(|key| metadata?.get(key))
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_BUDGET_KEY.to_string())
+    }
+
+    /// Returns the limits that apply to `key`.
+    pub fn limits_for(&self, key: &str) -> &BudgetLimits {
+        self.key_limits.get(key).unwrap_or(&self.default_limits)
+    }
+
+    /// Returns the current usage of `key`.
+    pub async fn usage(&self, key: &str) -> Result<BudgetUsage> {
+        self.store.usage(key, BudgetWindows::now()).await
+    }
+
+    /// Checks `key` against its limits and, if allowed, counts a new request.
+    ///
+    /// Returns [`Error::BudgetExceeded`] for the first limit that has been reached.
+    pub async fn acquire(&self, key: &str) -> Result<()> {
+        let limits = self.limits_for(key);
+        match self.store.try_record_request(key, BudgetWindows::now(), limits).await? {
+            Some(BudgetViolation { kind, used, limit }) => {
+                Err(Error::BudgetExceeded { key: key.to_string(), kind, used, limit })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Records the tokens and spend of a finished request against `key`.
+    pub async fn record(&self, key: &str, result: &impl UsageCost) -> Result<()> {
+        let Some(tokens) = result.token_counts() else {
+            return Ok(());
+        };
+        let total_tokens = (tokens.input_tokens + tokens.output_tokens).max(0) as u64;
+        let dollars = result.cost(&self.pricing).map(|cost| cost.total()).unwrap_or(0.0);
+        self.store.record_usage(key, BudgetWindows::now(), total_tokens, dollars).await
+    }
+
+    /// Passes a failure to record usage against `key` to the record error handler.
+    pub(crate) fn report_record_error(&self, key: &str, error: &Error) {
+        if let Some(handler) = &self.on_record_error {
+            handler(key, error);
+        }
+    }
+}
+
+impl fmt::Debug for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Budget")
+            .field("default_limits", &self.default_limits)
+            .field("key_limits", &self.key_limits)
+            .field("key_metadata", &self.key_metadata)
+            .field("pricing", &self.pricing)
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,312 @@
+// src/cache.rs
+
+//! Client-side caching of model responses.
+//!
+//! A [`ResponseCache`] attached to a [`Client`](crate::client::Client) answers
+//! `create_response`, `create_response_stream` and `create_chat_completion` requests whose
+//! parameters have been seen before without reaching the network. Requests are keyed by a
+//! SHA-256 hash of their canonical JSON, ignoring the `stream` flags and any fields
+//! configured with [`ResponseCache::ignore_field`]; cached responses are replayed to
+//! streaming requests as a synthetic event stream. Entries are kept in a [`CacheBackend`]:
+//! [`MemoryCache`] (an LRU) and [`DiskCache`] (one JSON file per entry) are provided.
+//!
+//! Only completed responses are cached. Cache hits skip moderation, budgets and cost
+//! tracking, since no request is sent.
+
+use crate::client::{Error, Result};
+use crate::models::responses::{Response, ResponseOutputItem, ResponseStatus, ResponseStreamEvent};
+use futures_util::future::BoxFuture;
+use lru::LruCache;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Request fields that never affect the cache key.
+const STREAM_FIELDS: [&str; 2] = ["stream", "stream_options"];
+
+//=======================================================================================
+// Backends
+//=======================================================================================
+
+/// A cached value and when it was stored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CacheEntry {
+    /// Unix timestamp (in milliseconds) of when the entry was stored.
+    pub stored_at_ms: u64,
+    pub value: Value,
+}
+
+/// Storage for cache entries. Object-safe, so shared backends (e.g. Redis) can be plugged in.
+pub trait CacheBackend: fmt::Debug + Send + Sync {
+    /// Returns the entry stored under `key`, if any.
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<CacheEntry>>>;
+
+    /// Stores `entry` under `key`, replacing any previous entry.
+    fn put<'a>(&'a self, key: &'a str, entry: CacheEntry) -> BoxFuture<'a, Result<()>>;
+}
+
+/// An in-memory backend that evicts the least recently used entry when full.
+#[derive(Debug)]
+pub struct MemoryCache {
+    entries: Mutex<LruCache<String, CacheEntry>>,
+}
+
+impl MemoryCache {
+    /// Creates a cache holding up to `capacity` entries (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        MemoryCache { entries: Mutex::new(LruCache::new(capacity)) }
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<CacheEntry>>> {
+        let entry = self.entries.lock().unwrap().get(key).cloned();
+        Box::pin(async move { Ok(entry) })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, entry: CacheEntry) -> BoxFuture<'a, Result<()>> {
+        self.entries.lock().unwrap().put(key.to_string(), entry);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// A backend that stores each entry as a JSON file in a directory.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Creates a cache in `dir`, creating the directory if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(DiskCache { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+impl CacheBackend for DiskCache {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<CacheEntry>>> {
+        Box::pin(async move {
+            match tokio::fs::read(self.path(key)).await {
+                Ok(json) => Ok(Some(serde_json::from_slice(&json)?)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(Error::UnexpectedResponse(format!("Failed to read cache entry: {err}"))),
+            }
+        })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, entry: CacheEntry) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let json = serde_json::to_vec(&entry)?;
+            tokio::fs::write(self.path(key), json)
+                .await
+                .map_err(|err| Error::UnexpectedResponse(format!("Failed to write cache entry: {err}")))
+        })
+    }
+}
+
+//=======================================================================================
+// Response Cache
+//=======================================================================================
+
+type ErrorHandler = Arc<dyn Fn(&str, &Error) + Send + Sync>;
+
+/// Decides how requests are keyed and how long their responses are reused.
+#[derive(Clone)]
+pub struct ResponseCache {
+    backend: Arc<dyn CacheBackend>,
+    ttl: Option<Duration>,
+    ignored_fields: Vec<String>,
+    on_error: Option<ErrorHandler>,
+}
+
+impl ResponseCache {
+    /// Creates a cache over `backend` whose entries never expire.
+    pub fn new(backend: Arc<dyn CacheBackend>) -> Self {
+        ResponseCache { backend, ttl: None, ignored_fields: Vec::new(), on_error: None }
+    }
+
+    /// Creates a cache over a [`MemoryCache`] of `capacity` entries.
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::new(Arc::new(MemoryCache::new(capacity)))
+    }
+
+    /// Treats entries older than `ttl` as missing.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Leaves the top-level request field `name` (e.g. `user` or `metadata`) out of the key,
+    /// so requests differing only in that field share an entry.
+    pub fn ignore_field(mut self, name: impl Into<String>) -> Self {
+        self.ignored_fields.push(name.into());
+        self
+    }
+
+    /// Calls `handler` with the key and error when the backend fails to read or store an
+    /// entry. Such failures are otherwise ignored: reads count as misses and requests are
+    /// sent as if there were no cache.
+    pub fn with_error_handler(mut self, handler: impl Fn(&str, &Error) + Send + Sync + 'static) -> Self {
+        self.on_error = Some(Arc::new(handler));
+        self
+    }
+
+    /// The cache key of a request to `endpoint` with `params`.
+    pub fn key(&self, endpoint: &str, params: &impl Serialize) -> Result<String> {
+        let mut value = serde_json::to_value(params)?;
+        if let Value::Object(map) = &mut value {
+            for field in STREAM_FIELDS.iter().copied().chain(self.ignored_fields.iter().map(String::as_str)) {
+                map.remove(field);
+            }
+        }
+        let mut canonical = format!("{endpoint}\n");
+        write_canonical(&value, &mut canonical);
+        Ok(format!("{:x}", Sha256::digest(canonical.as_bytes())))
+    }
+
+    /// Returns the unexpired value cached under `key`, if any.
+    pub(crate) async fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let entry = match self.backend.get(key).await {
+            Ok(entry) => entry?,
+            Err(err) => {
+                self.report_error(key, &err);
+                return None;
+            }
+        };
+        if let Some(ttl) = self.ttl
+            && Duration::from_millis(now_ms().saturating_sub(entry.stored_at_ms)) >= ttl
+        {
+            return None;
+        }
+        serde_json::from_value(entry.value).ok()
+    }
+
+    /// Caches `value` under `key`.
+    pub(crate) async fn put(&self, key: &str, value: &impl Serialize) {
+        let entry = match serde_json::to_value(value) {
+            Ok(value) => CacheEntry { stored_at_ms: now_ms(), value },
+            Err(_) => return,
+        };
+        if let Err(err) = self.backend.put(key, entry).await {
+            self.report_error(key, &err);
+        }
+    }
+
+    fn report_error(&self, key: &str, error: &Error) {
+        if let Some(handler) = &self.on_error {
+            handler(key, error);
+        }
+    }
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("backend", &self.backend)
+            .field("ttl", &self.ttl)
+            .field("ignored_fields", &self.ignored_fields)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether a response may be cached.
+pub(crate) fn is_cacheable(response: &Response) -> bool {
+    matches!(response.status, None | Some(ResponseStatus::Completed))
+}
+
+/// Writes `value` as JSON with object keys sorted, so equal values hash equally.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default()
+}
+
+//=======================================================================================
+// Synthetic Streams
+//=======================================================================================
+
+/// The events a stream producing `response` would have emitted: `response.created`, then
+/// `output_item.added`, any `output_text.delta`/`output_text.done` and `output_item.done`
+/// per output item, then `response.completed`. Each text is sent as a single delta.
+pub fn replay_events(response: &Response) -> Result<Vec<ResponseStreamEvent>> {
+    let mut events = Vec::new();
+    let mut push = |mut event: Value| -> Result<()> {
+        event["sequence_number"] = events.len().into();
+        events.push(serde_json::from_value(event).map_err(Error::from)?);
+        Ok(())
+    };
+
+    let mut created = serde_json::to_value(response)?;
+    created["status"] = "in_progress".into();
+    created["output"] = Value::Array(Vec::new());
+    push(serde_json::json!({ "type": "response.created", "response": created }))?;
+
+    for (output_index, item) in response.output.iter().enumerate() {
+        let item_json = serde_json::to_value(item)?;
+        push(serde_json::json!({ "type": "response.output_item.added", "output_index": output_index, "item": item_json }))?;
+        if let ResponseOutputItem::Message(message) = item {
+            let content = item_json["content"].as_array().cloned().unwrap_or_default();
+            for (content_index, part) in content.iter().enumerate() {
+                let Some(text) = part.get("text").filter(|_| part["type"] == "output_text") else {
+                    continue;
+                };
+                let text_event = |kind: &str, key: &str| {
+                    serde_json::json!({
+                        "type": kind,
+                        "item_id": message.id,
+                        "output_index": output_index,
+                        "content_index": content_index,
+                        key: text,
+                    })
+                };
+                push(text_event("response.output_text.delta", "delta"))?;
+                push(text_event("response.output_text.done", "text"))?;
+            }
+        }
+        push(serde_json::json!({ "type": "response.output_item.done", "output_index": output_index, "item": item_json }))?;
+    }
+
+    push(serde_json::json!({ "type": "response.completed", "response": response }))?;
+    Ok(events)
+}
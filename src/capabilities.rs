@@ -0,0 +1,224 @@
+// src/capabilities.rs
+
+//! What each model supports, and validation of request parameters against it.
+//!
+//! Model IDs are plain strings, so nothing in the type system stops a request from
+//! sending `reasoning` to a non-reasoning model or `temperature` to an o-series model.
+//! A [`CapabilityRegistry`] attached to a [`Client`](crate::client::Client) catches those
+//! mistakes before the request is sent, failing with
+//! [`Error::IncompatibleParameters`](crate::client::Error::IncompatibleParameters).
+//! Models missing from the registry are not validated.
+
+use crate::cost::lookup_model;
+use crate::models::responses::{
+    EasyInputMessageContent, ResponseCreateParams, ResponseFormatTextConfig, ResponseInputContent,
+    ResponseInputItem, ResponseInputParam,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+//=======================================================================================
+// Capabilities
+//=======================================================================================
+
+/// The features and limits of a model.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Accepts a `reasoning` configuration.
+    pub reasoning: bool,
+    /// Accepts the `temperature` and `top_p` sampling parameters.
+    pub sampling: bool,
+    /// Accepts `tools`.
+    pub tools: bool,
+    /// Accepts image inputs.
+    pub image_input: bool,
+    /// Supports `json_schema` structured outputs.
+    pub structured_outputs: bool,
+    /// The maximum number of input and output tokens.
+    pub context_window: u64,
+    /// The maximum number of output tokens, including reasoning tokens.
+    pub max_output_tokens: u64,
+}
+
+/// A table of model capabilities keyed by model ID.
+///
+/// Lookups follow the same rules as [`PricingTable`](crate::cost::PricingTable): exact
+/// IDs first, then the base model of a dated snapshot or `-latest` alias. Other variants,
+/// such as `o1-mini` or `gpt-4o-audio-preview`, need their own entries.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(transparent)]
+pub struct CapabilityRegistry {
+    models: HashMap<String, ModelCapabilities>,
+}
+
+impl CapabilityRegistry {
+    /// Creates an empty registry.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the built-in capabilities of current models.
+    pub fn builtin() -> Self {
+        let reasoning = |image_input, context_window, max_output_tokens| ModelCapabilities {
+            reasoning: true,
+            sampling: false,
+            tools: true,
+            image_input,
+            structured_outputs: true,
+            context_window,
+            max_output_tokens,
+        };
+        let chat = |context_window, max_output_tokens| ModelCapabilities {
+            reasoning: false,
+            sampling: true,
+            tools: true,
+            image_input: true,
+            structured_outputs: true,
+            context_window,
+            max_output_tokens,
+        };
+
+        let entries = [
+            ("gpt-5", reasoning(true, 400_000, 128_000)),
+            ("gpt-5-mini", reasoning(true, 400_000, 128_000)),
+            ("gpt-5-nano", reasoning(true, 400_000, 128_000)),
+            // The chat variants (e.g. `gpt-5-chat-latest`) are non-reasoning models.
+            ("gpt-5-chat", chat(128_000, 16_384)),
+            ("o1", reasoning(true, 200_000, 100_000)),
+            ("o1-mini", ModelCapabilities { tools: false, structured_outputs: false, ..reasoning(false, 128_000, 65_536) }),
+            ("o3", reasoning(true, 200_000, 100_000)),
+            ("o3-mini", reasoning(false, 200_000, 100_000)),
+            ("o4-mini", reasoning(true, 200_000, 100_000)),
+            ("gpt-4.1", chat(1_047_576, 32_768)),
+            ("gpt-4.1-mini", chat(1_047_576, 32_768)),
+            ("gpt-4.1-nano", chat(1_047_576, 32_768)),
+            ("gpt-4o", chat(128_000, 16_384)),
+            ("gpt-4o-mini", chat(128_000, 16_384)),
+        ];
+
+        let mut registry = Self::empty();
+        for (model, capabilities) in entries {
+            registry.set(model, capabilities);
+        }
+        registry
+    }
+
+    /// Sets (or overrides) the capabilities of a model.
+    pub fn set(&mut self, model: impl Into<String>, capabilities: ModelCapabilities) {
+        self.models.insert(model.into(), capabilities);
+    }
+
+    /// Builder-style variant of [`CapabilityRegistry::set`].
+    pub fn with(mut self, model: impl Into<String>, capabilities: ModelCapabilities) -> Self {
+        self.set(model, capabilities);
+        self
+    }
+
+    /// Looks up the capabilities of a model.
+    pub fn get(&self, model: &str) -> Option<&ModelCapabilities> {
+        lookup_model(&self.models, model)
+    }
+
+    /// Checks `params` against the capabilities of its model.
+    ///
+    /// Returns every incompatibility found. Requests without a model, or for a model not
+    /// in the registry, always pass.
+    pub fn validate(&self, params: &ResponseCreateParams) -> Result<(), Vec<CapabilityViolation>> {
+        let Some(capabilities) = params.model.as_deref().and_then(|model| self.get(model)) else {
+            return Ok(());
+        };
+
+        let mut violations = Vec::new();
+        if params.reasoning.is_some() && !capabilities.reasoning {
+            violations.push(CapabilityViolation::ReasoningUnsupported);
+        }
+        if !capabilities.sampling {
+            if params.temperature.is_some() {
+                violations.push(CapabilityViolation::SamplingUnsupported { parameter: "temperature" });
+            }
+            if params.top_p.is_some() {
+                violations.push(CapabilityViolation::SamplingUnsupported { parameter: "top_p" });
+            }
+        }
+        if params.tools.as_ref().is_some_and(|tools| !tools.is_empty()) && !capabilities.tools {
+            violations.push(CapabilityViolation::ToolsUnsupported);
+        }
+        if !capabilities.image_input && params.input.as_ref().is_some_and(has_image_input) {
+            violations.push(CapabilityViolation::ImageInputUnsupported);
+        }
+        let json_schema = params
+            .text
+            .as_ref()
+            .is_some_and(|text| matches!(text.format, Some(ResponseFormatTextConfig::JsonSchema(_))));
+        if json_schema && !capabilities.structured_outputs {
+            violations.push(CapabilityViolation::StructuredOutputsUnsupported);
+        }
+        if let Some(requested) = params.max_output_tokens
+            && requested > 0
+            && requested as u64 > capabilities.max_output_tokens
+        {
+            violations.push(CapabilityViolation::MaxOutputTokensExceeded {
+                requested: requested as u64,
+                max: capabilities.max_output_tokens,
+            });
+        }
+
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+}
+
+fn has_image_input(input: &ResponseInputParam) -> bool {
+    let ResponseInputParam::Items(items) = input else {
+        return false;
+    };
+    let is_image = |content: &ResponseInputContent| matches!(content, ResponseInputContent::InputImage(_));
+    items.iter().any(|item| match item {
+        ResponseInputItem::EasyMessage(message) => match &message.content {
+            EasyInputMessageContent::String(_) => false,
+            EasyInputMessageContent::List(content) => content.iter().any(is_image),
+        },
+        ResponseInputItem::Message(message) => message.content.iter().any(is_image),
+        _ => false,
+    })
+}
+
+//=======================================================================================
+// Violations
+//=======================================================================================
+
+/// A request parameter the model does not support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityViolation {
+    /// `reasoning` was set for a model without reasoning.
+    ReasoningUnsupported,
+    /// A sampling parameter was set for a model that ignores or rejects it.
+    SamplingUnsupported { parameter: &'static str },
+    /// `tools` were given to a model without tool support.
+    ToolsUnsupported,
+    /// Images were given to a model without image input.
+    ImageInputUnsupported,
+    /// A `json_schema` format was requested from a model without structured outputs.
+    StructuredOutputsUnsupported,
+    /// `max_output_tokens` exceeds the model's output limit.
+    MaxOutputTokensExceeded { requested: u64, max: u64 },
+}
+
+impl fmt::Display for CapabilityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CapabilityViolation::ReasoningUnsupported => write!(f, "model does not support reasoning"),
+            CapabilityViolation::SamplingUnsupported { parameter } => {
+                write!(f, "model does not support `{parameter}`")
+            }
+            CapabilityViolation::ToolsUnsupported => write!(f, "model does not support tools"),
+            CapabilityViolation::ImageInputUnsupported => write!(f, "model does not accept image input"),
+            CapabilityViolation::StructuredOutputsUnsupported => {
+                write!(f, "model does not support structured outputs")
+            }
+            CapabilityViolation::MaxOutputTokensExceeded { requested, max } => {
+                write!(f, "max_output_tokens {requested} exceeds the model limit of {max}")
+            }
+        }
+    }
+}
@@ -2,15 +2,37 @@
 
 //! An asynchronous, production-ready client for the API, built on top of `reqwest`.
 
+use crate::budget::{Budget, BudgetLimitKind};
+use crate::cache::{self, ResponseCache};
+use crate::capabilities::{CapabilityRegistry, CapabilityViolation};
+use crate::cost::{CostTracker, UsageCost};
+use crate::interceptor::{Interceptor, InterceptorChain, RequestInfo};
 use crate::models;
+use crate::models::outcome::ResponseOutcome;
+use crate::moderation::ModerationGuard;
+use crate::rate_limit::{self, RateLimiter};
+use crate::transport::{HttpTransport, ReqwestTransport};
 use reqwest::{header, Client as ReqwestClient, Method, RequestBuilder, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::error::Error as StdError;
 use std::fmt;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use bytes::Bytes;
 use futures_util::{Stream, StreamExt, TryStreamExt};
 
+pub mod batches;
+pub mod files;
+pub mod vector_stores;
+pub mod embeddings;
+pub mod moderations;
+pub mod audio;
+pub mod images;
+pub mod model;
+pub mod raw;
+pub mod background;
+
 const API_BASE_URL: &str = "https://api.openai.com/v1";
 
 //=======================================================================================
@@ -32,6 +54,31 @@ pub enum Error {
     /// An error for responses that are not successful but don't match the
     /// expected API error format.
     UnexpectedResponse(String),
+    /// The request was refused locally because a client-side budget was exhausted.
+    BudgetExceeded {
+        key: String,
+        kind: BudgetLimitKind,
+        used: f64,
+        limit: f64,
+    },
+    /// The request was refused locally because pre-flight moderation flagged its user
+    /// input.
+    ModerationFlagged {
+        categories: Vec<String>,
+        moderation: Box<models::moderations::ModerationCreateResponse>,
+    },
+    /// The request was refused locally because its parameters are not supported by the
+    /// model, according to the client's [`CapabilityRegistry`].
+    IncompatibleParameters {
+        model: String,
+        violations: Vec<CapabilityViolation>,
+    },
+    /// The response failed or is incomplete, and the client is configured to treat that
+    /// as an error (see [`Client::with_outcome_errors`]).
+    ResponseUnsuccessful {
+        outcome: ResponseOutcome,
+        response: Box<models::responses::Response>,
+    },
 }
 
 impl fmt::Display for Error {
@@ -43,6 +90,19 @@ impl fmt::Display for Error {
                 write!(f, "API error (status {}): [{:?}] {}", status, error.code, error.message)
             }
             Error::UnexpectedResponse(msg) => write!(f, "Unexpected API response: {msg}"),
+            Error::BudgetExceeded { key, kind, used, limit } => {
+                write!(f, "Budget exceeded for key '{key}': {used} of {limit} {kind}")
+            }
+            Error::ModerationFlagged { categories, .. } => {
+                write!(f, "Input flagged by moderation: {}", categories.join(", "))
+            }
+            Error::IncompatibleParameters { model, violations } => {
+                let violations: Vec<String> = violations.iter().map(ToString::to_string).collect();
+                write!(f, "Incompatible parameters for model '{model}': {}", violations.join("; "))
+            }
+            Error::ResponseUnsuccessful { outcome, response } => {
+                write!(f, "Response '{}' did not complete: {outcome}", response.id)
+            }
         }
     }
 }
@@ -82,7 +142,17 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug, Clone)]
 pub struct Client {
     http_client: ReqwestClient,
+    default_headers: header::HeaderMap,
+    transport: Arc<dyn HttpTransport>,
     base_url: String,
+    cost_tracker: Option<Arc<CostTracker>>,
+    budget: Option<Arc<Budget>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    moderation: Option<Arc<ModerationGuard>>,
+    capabilities: Option<Arc<CapabilityRegistry>>,
+    cache: Option<Arc<ResponseCache>>,
+    outcome_errors: bool,
+    interceptors: InterceptorChain,
 }
 
 impl Client {
@@ -108,14 +178,243 @@ impl Client {
         );
 
         let http_client = ReqwestClient::builder()
-            .default_headers(headers)
             .timeout(Duration::from_secs(60))
             .build()
             .expect("Failed to build ReqwestClient");
 
         Client {
+            transport: Arc::new(ReqwestTransport::new(http_client.clone())),
             http_client,
+            default_headers: headers,
             base_url,
+            cost_tracker: None,
+            budget: None,
+            rate_limiter: None,
+            moderation: None,
+            capabilities: None,
+            cache: None,
+            outcome_errors: false,
+            interceptors: InterceptorChain::default(),
+        }
+    }
+
+    /// Replaces the [`HttpTransport`] requests are sent with, e.g. with an
+    /// [`InMemoryTransport`](crate::transport::InMemoryTransport) in tests.
+    pub fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Returns the [`HttpTransport`] requests are sent with.
+    pub fn transport(&self) -> &Arc<dyn HttpTransport> {
+        &self.transport
+    }
+
+    /// Attaches a [`CostTracker`] that records the spend of every completed request.
+    ///
+    /// The tracker is shared, so clones of this client record into the same totals.
+    pub fn with_cost_tracker(mut self, tracker: Arc<CostTracker>) -> Self {
+        self.cost_tracker = Some(tracker);
+        self
+    }
+
+    /// Returns the attached [`CostTracker`], if any.
+    pub fn cost_tracker(&self) -> Option<&Arc<CostTracker>> {
+        self.cost_tracker.as_ref()
+    }
+
+    /// Attaches a [`Budget`] that is checked before every create request.
+    ///
+    /// Requests over budget fail with [`Error::BudgetExceeded`] without being sent.
+    pub fn with_budget(mut self, budget: Arc<Budget>) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Returns the attached [`Budget`], if any.
+    pub fn budget(&self) -> Option<&Arc<Budget>> {
+        self.budget.as_ref()
+    }
+
+    /// Attaches a [`RateLimiter`] that queues create requests per model instead of
+    /// letting them fail with `429 Too Many Requests`.
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Returns the attached [`RateLimiter`], if any.
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Attaches a [`ModerationGuard`] that screens the user input of every
+    /// `create_response` request before it is sent.
+    ///
+    /// Flagged requests fail with [`Error::ModerationFlagged`].
+    pub fn with_moderation(mut self, guard: Arc<ModerationGuard>) -> Self {
+        self.moderation = Some(guard);
+        self
+    }
+
+    /// Returns the attached [`ModerationGuard`], if any.
+    pub fn moderation(&self) -> Option<&Arc<ModerationGuard>> {
+        self.moderation.as_ref()
+    }
+
+    /// Attaches a [`CapabilityRegistry`] that `create_response` requests are validated
+    /// against before they are sent.
+    ///
+    /// Invalid requests fail with [`Error::IncompatibleParameters`].
+    pub fn with_capabilities(mut self, registry: Arc<CapabilityRegistry>) -> Self {
+        self.capabilities = Some(registry);
+        self
+    }
+
+    /// Returns the attached [`CapabilityRegistry`], if any.
+    pub fn capabilities(&self) -> Option<&Arc<CapabilityRegistry>> {
+        self.capabilities.as_ref()
+    }
+
+    /// Attaches a [`ResponseCache`] that answers repeated `create_response`,
+    /// `create_response_stream` and `create_chat_completion` requests without sending them.
+    pub fn with_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Returns the attached [`ResponseCache`], if any.
+    pub fn cache(&self) -> Option<&Arc<ResponseCache>> {
+        self.cache.as_ref()
+    }
+
+    /// Makes `create_response` return [`Error::ResponseUnsuccessful`] for responses that
+    /// failed or are incomplete, instead of returning them.
+    pub fn with_outcome_errors(mut self, enabled: bool) -> Self {
+        self.outcome_errors = enabled;
+        self
+    }
+
+    /// Returns whether failed and incomplete responses are returned as errors.
+    pub fn outcome_errors(&self) -> bool {
+        self.outcome_errors
+    }
+
+    /// Adds an [`Interceptor`] that every request sent by this client passes through.
+    ///
+    /// Interceptors run in the order they are added.
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn Interceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Returns the attached interceptors, in the order they run.
+    pub fn interceptors(&self) -> &[Arc<dyn Interceptor>] {
+        self.interceptors.as_slice()
+    }
+
+    /// Validates a request against the model's capabilities, if a registry is attached.
+    fn validate(&self, params: &models::responses::ResponseCreateParams) -> Result<()> {
+        let Some(registry) = &self.capabilities else {
+            return Ok(());
+        };
+        registry.validate(params).map_err(|violations| Error::IncompatibleParameters {
+            model: params.model.clone().unwrap_or_default(),
+            violations,
+        })
+    }
+
+    /// Runs pre-flight moderation on the user input of a request, if a guard is attached.
+    async fn moderate(&self, params: &models::responses::ResponseCreateParams) -> Result<()> {
+        let Some(guard) = &self.moderation else {
+            return Ok(());
+        };
+        let Some(moderation_params) = guard.params_for(params) else {
+            return Ok(());
+        };
+        let moderation = moderations::create_moderation(self, moderation_params).await?;
+        let categories = guard.violations(&moderation);
+        if categories.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ModerationFlagged { categories, moderation: Box::new(moderation) })
+        }
+    }
+
+    /// Builds the rate-limit scope of a request, if a limiter is attached.
+    fn rate_limit_scope(
+        &self,
+        model: Option<&str>,
+        body: &impl Serialize,
+        max_output_tokens: Option<i64>,
+    ) -> Option<RateLimitScope> {
+        self.rate_limiter.as_ref().map(|_| RateLimitScope {
+            model: model.unwrap_or_default().to_string(),
+            tokens: RateLimiter::estimate_tokens(body, max_output_tokens),
+        })
+    }
+
+    /// The cache key of a request, if a cache is attached.
+    fn cache_key(&self, endpoint: &str, params: &impl Serialize) -> Result<Option<String>> {
+        self.cache.as_ref().map(|cache| cache.key(endpoint, params)).transpose()
+    }
+
+    /// Looks up a cached result by key.
+    async fn cached<T: DeserializeOwned>(&self, key: Option<&str>) -> Option<T> {
+        let (Some(cache), Some(key)) = (&self.cache, key) else {
+            return None;
+        };
+        let value: serde_json::Value = cache.get(key).await?;
+        let cached = serde_json::from_value(value.clone()).ok()?;
+        raw::record_cache_hit(Bytes::from(value.to_string()));
+        Some(cached)
+    }
+
+    /// Caches a result by key.
+    async fn store(&self, key: Option<&str>, value: &impl Serialize) {
+        if let (Some(cache), Some(key)) = (&self.cache, key) {
+            cache.put(key, value).await;
+        }
+    }
+
+    /// Checks the budget for a request and returns a recorder for its usage.
+    async fn begin_usage(&self, metadata: Option<&models::responses::Metadata>) -> Result<UsageRecorder> {
+        let budget = match &self.budget {
+            Some(budget) => {
+                let key = budget.key_for(metadata);
+                budget.acquire(&key).await?;
+                Some((budget.clone(), key))
+            }
+            None => None,
+        };
+        let cost = self.cost_tracker.as_ref().map(|t| (t.clone(), t.tag_for(metadata)));
+        Ok(UsageRecorder { cost, budget })
+    }
+}
+
+/// The model and estimated token cost a request is rate limited under.
+#[derive(Debug, Clone)]
+struct RateLimitScope {
+    model: String,
+    tokens: u64,
+}
+
+/// Records the usage of a finished request against the client's tracker and budget.
+#[derive(Debug, Clone)]
+struct UsageRecorder {
+    cost: Option<(Arc<CostTracker>, String)>,
+    budget: Option<(Arc<Budget>, String)>,
+}
+
+impl UsageRecorder {
+    async fn record(&self, result: &impl UsageCost) {
+        if let Some((tracker, tag)) = &self.cost {
+            tracker.record(tag, result);
+        }
+        if let Some((budget, key)) = &self.budget
+            && let Err(err) = budget.record(key, result).await
+        {
+            budget.report_record_error(key, &err);
         }
     }
 }
@@ -132,7 +431,26 @@ pub async fn create_response(
     mut params: models::responses::ResponseCreateParams,
 ) -> Result<models::responses::Response> {
     params.stream = Some(false);
-    execute_request_with_body(client, Method::POST, "/responses", Some(params)).await
+    client.validate(&params)?;
+    let cache_key = client.cache_key("/responses", &params)?;
+    if let Some(response) = client.cached(cache_key.as_deref()).await {
+        return Ok(response);
+    }
+    client.moderate(&params).await?;
+    let usage = client.begin_usage(params.metadata.as_ref()).await?;
+    let scope = client.rate_limit_scope(params.model.as_deref(), &params, params.max_output_tokens);
+    let response: models::responses::Response =
+        execute_request_with_body(client, Method::POST, "/responses", Some(params), scope).await?;
+    usage.record(&response).await;
+    if cache::is_cacheable(&response) {
+        client.store(cache_key.as_deref(), &response).await;
+    }
+    match response.outcome() {
+        outcome @ (ResponseOutcome::Failed(_) | ResponseOutcome::Incomplete { .. }) if client.outcome_errors => {
+            Err(Error::ResponseUnsuccessful { outcome, response: Box::new(response) })
+        }
+        _ => Ok(response),
+    }
 }
 
 /// Creates a model response as a stream of events.
@@ -143,10 +461,36 @@ pub async fn create_response_stream(
     mut params: models::responses::ResponseCreateParams,
 ) -> Result<impl Stream<Item = Result<models::responses::ResponseStreamEvent>>> {
     params.stream = Some(true);
+    client.validate(&params)?;
+    let cache_key = client.cache_key("/responses", &params)?;
+    if let Some(response) = client.cached(cache_key.as_deref()).await {
+        let events = cache::replay_events(&response)?;
+        return Ok(futures_util::stream::iter(events.into_iter().map(Ok)).left_stream());
+    }
+    client.moderate(&params).await?;
+    let usage = client.begin_usage(params.metadata.as_ref()).await?;
+    let scope = client.rate_limit_scope(params.model.as_deref(), &params, params.max_output_tokens);
     let request_builder = client.http_client
         .post(format!("{}{}", client.base_url, "/responses"))
         .json(&params);
-    execute_stream(request_builder).await
+    let stream = execute_stream::<models::responses::ResponseStreamEvent>(client, request_builder, scope).await?;
+    let cache = client.cache.clone();
+    Ok(stream.then(move |event| {
+        let usage = usage.clone();
+        let cache = cache.clone();
+        let cache_key = cache_key.clone();
+        async move {
+            if let Ok(models::responses::ResponseStreamEvent::ResponseCompleted(completed)) = &event {
+                usage.record(completed).await;
+                if let (Some(cache), Some(key)) = (cache, cache_key)
+                    && cache::is_cacheable(&completed.response)
+                {
+                    cache.put(&key, &completed.response).await;
+                }
+            }
+            event
+        }
+    }).right_stream())
 }
 
 /// Retrieves a model response with the given ID.
@@ -160,7 +504,7 @@ pub async fn retrieve_response(
     let path = format!("/responses/{response_id}");
     let url = format!("{}{}", client.base_url, &path);
     let rb = client.http_client.get(&url).query(&params);
-    execute_request(rb).await
+    execute_request(client, rb, None).await
 }
 
 /// Retrieves a model response as a stream of events.
@@ -175,7 +519,7 @@ pub async fn retrieve_response_stream(
     let path = format!("/responses/{response_id}");
     let url = format!("{}{}", client.base_url, &path);
     let rb = client.http_client.get(&url).query(&params);
-    execute_stream(rb).await
+    execute_stream::<models::responses::ResponseStreamEvent>(client, rb, None).await
 }
 
 /// Deletes a model response with the given ID.
@@ -183,10 +527,8 @@ pub async fn retrieve_response_stream(
 /// [API Documentation](https://platform.openai.com/docs/api-reference/responses/delete)
 pub async fn delete_response(client: &Client, response_id: &str) -> Result<()> {
     let path = format!("/responses/{response_id}");
-    let response = client.http_client
-        .delete(format!("{}{}", client.base_url, &path))
-        .send()
-        .await?;
+    let rb = client.http_client.delete(format!("{}{}", client.base_url, &path));
+    let response = send_request(client, rb, None).await?;
 
     if response.status().is_success() {
         Ok(())
@@ -207,7 +549,7 @@ pub async fn cancel_response(
     response_id: &str,
 ) -> Result<models::responses::Response> {
     let path = format!("/responses/{response_id}/cancel");
-    execute_request_with_body(client, Method::POST, &path, None::<()>).await
+    execute_request_with_body(client, Method::POST, &path, None::<()>, None).await
 }
 
 //=======================================================================================
@@ -222,7 +564,17 @@ pub async fn create_chat_completion(
     mut params: models::responses::ChatCompletionCreateParams,
 ) -> Result<models::responses::ChatCompletion> {
     params.stream = Some(false);
-    execute_request_with_body(client, Method::POST, "/chat/completions", Some(params)).await
+    let cache_key = client.cache_key("/chat/completions", &params)?;
+    if let Some(completion) = client.cached(cache_key.as_deref()).await {
+        return Ok(completion);
+    }
+    let usage = client.begin_usage(params.metadata.as_ref()).await?;
+    let scope = client.rate_limit_scope(Some(&params.model), &params, params.max_tokens);
+    let completion: models::responses::ChatCompletion =
+        execute_request_with_body(client, Method::POST, "/chat/completions", Some(params), scope).await?;
+    usage.record(&completion).await;
+    client.store(cache_key.as_deref(), &completion).await;
+    Ok(completion)
 }
 
 //=======================================================================================
@@ -235,6 +587,7 @@ async fn execute_request_with_body<T: DeserializeOwned, B: Serialize>(
     method: Method,
     path: &str,
     body: Option<B>,
+    scope: Option<RateLimitScope>,
 ) -> Result<T> {
     let url = format!("{}{}", client.base_url, path);
     let mut request_builder = client.http_client.request(method, &url);
@@ -243,17 +596,99 @@ async fn execute_request_with_body<T: DeserializeOwned, B: Serialize>(
         request_builder = request_builder.json(&b);
     }
 
-    execute_request(request_builder).await
+    execute_request(client, request_builder, scope).await
+}
+
+/// Sends a pre-built request, queuing it behind the client's rate limiter when a scope
+/// is given. Requests rejected with `429` are re-queued up to the limiter's retry limit.
+async fn send_request(
+    client: &Client,
+    rb: RequestBuilder,
+    scope: Option<RateLimitScope>,
+) -> Result<reqwest::Response> {
+    let sent_at = Instant::now();
+    let request = rb.build()?;
+    let (Some(limiter), Some(scope)) = (&client.rate_limiter, scope) else {
+        let response = dispatch(client, request).await?;
+        raw::record_head(sent_at, &response);
+        return Ok(response);
+    };
+
+    let mut attempt = 0;
+    loop {
+        let request = request
+            .try_clone()
+            .ok_or_else(|| Error::UnexpectedResponse("Request body cannot be retried".into()))?;
+        limiter.acquire(&scope.model, scope.tokens).await;
+        let response = dispatch(client, request).await?;
+        limiter.observe(&scope.model, response.headers());
+
+        if response.status() != StatusCode::TOO_MANY_REQUESTS || attempt >= limiter.max_retries() {
+            raw::record_head(sent_at, &response);
+            return Ok(response);
+        }
+        attempt += 1;
+        let retry_after = response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok()?.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| rate_limit::retry_backoff(attempt));
+        limiter.back_off(&scope.model, retry_after);
+    }
+}
+
+/// Sends a single request through the client's interceptors and transport.
+async fn dispatch(client: &Client, mut request: reqwest::Request) -> Result<reqwest::Response> {
+    for (name, value) in &client.default_headers {
+        if !request.headers().contains_key(name) {
+            request.headers_mut().insert(name, value.clone());
+        }
+    }
+
+    let interceptors = client.interceptors.as_slice();
+    let started = Instant::now();
+    let mut ran = interceptors.len();
+    let mut canned = None;
+    for (i, interceptor) in interceptors.iter().enumerate() {
+        if let Some(response) = interceptor.before_request(&mut request).await? {
+            canned = Some(response);
+            ran = i + 1;
+            break;
+        }
+    }
+
+    let info = (!interceptors.is_empty()).then(|| RequestInfo {
+        method: request.method().clone(),
+        url: request.url().clone(),
+        headers: request.headers().clone(),
+        elapsed: Duration::ZERO,
+    });
+    let mut response = match canned {
+        Some(response) => response,
+        None => client.transport.send(request).await?,
+    };
+    if let Some(mut info) = info {
+        info.elapsed = started.elapsed();
+        for interceptor in interceptors[..ran].iter().rev() {
+            response = interceptor.after_response(&info, response).await?;
+        }
+    }
+    Ok(response)
 }
 
 /// Executes a pre-built request and handles the response.
-async fn execute_request<T: DeserializeOwned>(rb: RequestBuilder) -> Result<T> {
-    let response = rb.send().await?;
+async fn execute_request<T: DeserializeOwned>(
+    client: &Client,
+    rb: RequestBuilder,
+    scope: Option<RateLimitScope>,
+) -> Result<T> {
+    let response = send_request(client, rb, scope).await?;
     let status = response.status();
 
     if status.is_success() {
-        let text_body = response.text().await?;
-        let mut deserializer = serde_json::Deserializer::from_str(&text_body);
+        let body = read_body(response).await?;
+        let mut deserializer = serde_json::Deserializer::from_slice(&body);
         match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
             Ok(json_body) => Ok(json_body),
             Err(err) => {
@@ -263,27 +698,40 @@ async fn execute_request<T: DeserializeOwned>(rb: RequestBuilder) -> Result<T> {
             }
         }
     } else {
-        let error_body = response.text().await?;
-        match serde_json::from_str::<models::responses::ResponseError>(&error_body) {
-            Ok(api_error) => Err(Error::ApiError { status, error: api_error }),
-            Err(_) => Err(Error::UnexpectedResponse(format!("Status: {status}, Body: {error_body}"))),
-        }
+        Err(error_from_response(response).await)
+    }
+}
+
+/// Reads the full body of a successful response.
+async fn read_body(response: reqwest::Response) -> Result<Bytes> {
+    let body = response.bytes().await?;
+    raw::record_body(&body);
+    Ok(body)
+}
+
+/// Converts an unsuccessful response into an [`Error`].
+async fn error_from_response(response: reqwest::Response) -> Error {
+    let status = response.status();
+    let error_body = match response.text().await {
+        Ok(body) => body,
+        Err(e) => return Error::from(e),
+    };
+    match serde_json::from_str::<models::responses::ResponseError>(&error_body) {
+        Ok(api_error) => Error::ApiError { status, error: api_error },
+        Err(_) => Error::UnexpectedResponse(format!("Status: {status}, Body: {error_body}")),
     }
 }
 
 /// Executes a request and returns a stream of Server-Sent Events.
-async fn execute_stream(
+async fn execute_stream<T: DeserializeOwned>(
+    client: &Client,
     rb: RequestBuilder,
-) -> Result<impl Stream<Item = Result<models::responses::ResponseStreamEvent>>> {
-    let response = rb.send().await?;
+    scope: Option<RateLimitScope>,
+) -> Result<impl Stream<Item = Result<T>>> {
+    let response = send_request(client, rb, scope).await?;
 
     if !response.status().is_success() {
-        let status = response.status();
-        let error_body = response.text().await?;
-        return match serde_json::from_str::<models::responses::ResponseError>(&error_body) {
-            Ok(api_error) => Err(Error::ApiError { status, error: api_error }),
-            Err(_) => Err(Error::UnexpectedResponse(format!("Status: {status}, Body: {error_body}"))),
-        };
+        return Err(error_from_response(response).await);
     }
 
     let byte_stream = response.bytes_stream();
@@ -311,11 +759,8 @@ async fn execute_stream(
                         continue; // Skip empty keep-alive messages
                     }
 
-                    // Debug: Print the raw JSON being received
-                    println!("🔍 Raw JSON received from stream: {data_buf}");
-
                     let mut deserializer = serde_json::Deserializer::from_str(&data_buf);
-                    let event_result = serde_path_to_error::deserialize::<_, models::responses::ResponseStreamEvent>(&mut deserializer);
+                    let event_result = serde_path_to_error::deserialize::<_, T>(&mut deserializer);
                     match event_result {
                         Ok(event) => return Ok(Some((event, (stream, buffer)))),
                         Err(err) => {
@@ -0,0 +1,123 @@
+// src/client/audio.rs
+
+//! Audio API functions: transcription, translation and speech.
+
+use super::{error_from_response, execute_stream, read_body, send_request, Client, Error, Result};
+use crate::models::audio::{
+    AudioResponseFormat, SpeechCreateParams, Transcription, TranscriptionCreateParams,
+    TranscriptionStreamEvent, Translation, TranslationCreateParams,
+};
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use reqwest::multipart::{Form, Part};
+
+/// Transcribes audio into the input language.
+///
+/// Plain-text response formats (`text`, `srt`, `vtt`) are returned verbatim in
+/// [`Transcription::text`]. Use [`create_transcription_stream`] to stream the transcript.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/audio/createTranscription)
+pub async fn create_transcription(client: &Client, mut params: TranscriptionCreateParams) -> Result<Transcription> {
+    params.stream = None;
+    let format = params.response_format;
+    let form = transcription_form(params);
+    send_audio_form(client, "/audio/transcriptions", form, format).await
+}
+
+/// Transcribes audio, streaming the transcript as it is produced.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/audio/createTranscription)
+pub async fn create_transcription_stream(
+    client: &Client,
+    mut params: TranscriptionCreateParams,
+) -> Result<impl Stream<Item = Result<TranscriptionStreamEvent>>> {
+    params.stream = Some(true);
+    let form = transcription_form(params);
+    let rb = client.http_client
+        .post(format!("{}{}", client.base_url, "/audio/transcriptions"))
+        .multipart(form);
+    execute_stream::<TranscriptionStreamEvent>(client, rb, None).await
+}
+
+/// Translates audio into English.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/audio/createTranslation)
+pub async fn create_translation(client: &Client, params: TranslationCreateParams) -> Result<Translation> {
+    let format = params.response_format;
+    let mut form = Form::new()
+        .text("model", params.model)
+        .part("file", Part::bytes(params.file).file_name(params.filename));
+    if let Some(prompt) = params.prompt {
+        form = form.text("prompt", prompt);
+    }
+    if let Some(format) = format {
+        form = form.text("response_format", format.as_str());
+    }
+    if let Some(temperature) = params.temperature {
+        form = form.text("temperature", temperature.to_string());
+    }
+    send_audio_form(client, "/audio/translations", form, format).await
+}
+
+/// Generates audio from text, returned as a stream of chunks in the requested format.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/audio/createSpeech)
+pub async fn create_speech(
+    client: &Client,
+    params: SpeechCreateParams,
+) -> Result<impl Stream<Item = Result<Bytes>>> {
+    let rb = client.http_client
+        .post(format!("{}{}", client.base_url, "/audio/speech"))
+        .json(&params);
+    let response = send_request(client, rb, None).await?;
+    if !response.status().is_success() {
+        return Err(error_from_response(response).await);
+    }
+    Ok(response.bytes_stream().map_err(Into::into))
+}
+
+fn transcription_form(params: TranscriptionCreateParams) -> Form {
+    let mut form = Form::new()
+        .text("model", params.model)
+        .part("file", Part::bytes(params.file).file_name(params.filename));
+    if let Some(language) = params.language {
+        form = form.text("language", language);
+    }
+    if let Some(prompt) = params.prompt {
+        form = form.text("prompt", prompt);
+    }
+    if let Some(format) = params.response_format {
+        form = form.text("response_format", format.as_str());
+    }
+    if let Some(temperature) = params.temperature {
+        form = form.text("temperature", temperature.to_string());
+    }
+    for granularity in params.timestamp_granularities.unwrap_or_default() {
+        form = form.text("timestamp_granularities[]", granularity.as_str());
+    }
+    if let Some(stream) = params.stream {
+        form = form.text("stream", stream.to_string());
+    }
+    form
+}
+
+async fn send_audio_form(
+    client: &Client,
+    path: &str,
+    form: Form,
+    format: Option<AudioResponseFormat>,
+) -> Result<Transcription> {
+    let rb = client.http_client
+        .post(format!("{}{}", client.base_url, path))
+        .multipart(form);
+    let response = send_request(client, rb, None).await?;
+    if !response.status().is_success() {
+        return Err(error_from_response(response).await);
+    }
+
+    let body = read_body(response).await?;
+    if format.is_some_and(|format| format.is_plain_text()) {
+        return Ok(Transcription { text: String::from_utf8_lossy(&body).into_owned(), ..Default::default() });
+    }
+    serde_json::from_slice(&body).map_err(Error::from)
+}
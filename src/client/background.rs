@@ -0,0 +1,138 @@
+// src/client/background.rs
+
+//! Waiting for background responses.
+//!
+//! Responses created with `background: Some(true)` return immediately while still queued
+//! or in progress. [`wait_for_response`] polls them until they finish, with exponential
+//! backoff, an optional timeout and an optional cancellation token.
+
+use super::{cancel_response, retrieve_response, Client, Result};
+use crate::models::responses::{Response, ResponseStatus};
+use futures_util::future::{select, Either};
+use std::pin::pin;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+/// How [`wait_for_response`] polls.
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    /// The delay before the second poll.
+    pub initial_interval: Duration,
+    /// The longest delay between polls.
+    pub max_interval: Duration,
+    /// The factor the delay grows by after each poll. Invalid factors (negative or NaN)
+    /// poll at `max_interval`.
+    pub multiplier: f64,
+    /// How long to wait before giving up, if at all.
+    pub timeout: Option<Duration>,
+    /// Cancels the response (with `cancel_response`) when triggered.
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        PollOptions {
+            initial_interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(5),
+            multiplier: 1.5,
+            timeout: None,
+            cancellation: None,
+        }
+    }
+}
+
+impl PollOptions {
+    /// Sets the initial and longest delay between polls.
+    pub fn with_interval(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_interval = initial;
+        self.max_interval = max.max(initial);
+        self
+    }
+
+    /// Gives up after `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancels the response when `token` is triggered.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+}
+
+/// How waiting for a background response ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PollOutcome {
+    /// The response left `queued`/`in_progress`: it completed, failed, was cancelled
+    /// elsewhere or is incomplete.
+    Finished(Response),
+    /// The timeout elapsed first. Holds the last polled response, which is still pending.
+    TimedOut(Response),
+    /// The cancellation token was triggered. Holds the response returned by `cancel_response`.
+    Cancelled(Response),
+}
+
+impl PollOutcome {
+    /// The response the outcome holds.
+    pub fn response(&self) -> &Response {
+        match self {
+            PollOutcome::Finished(response) | PollOutcome::TimedOut(response) | PollOutcome::Cancelled(response) => {
+                response
+            }
+        }
+    }
+
+    /// Consumes the outcome, returning its response.
+    pub fn into_response(self) -> Response {
+        match self {
+            PollOutcome::Finished(response) | PollOutcome::TimedOut(response) | PollOutcome::Cancelled(response) => {
+                response
+            }
+        }
+    }
+}
+
+/// Polls a background response until it is no longer queued or in progress.
+///
+/// [API Documentation](https://platform.openai.com/docs/guides/background)
+pub async fn wait_for_response(client: &Client, response_id: &str, options: PollOptions) -> Result<PollOutcome> {
+    let started = Instant::now();
+    let mut interval = options.initial_interval;
+    loop {
+        if options.cancellation.as_ref().is_some_and(CancellationToken::is_cancelled) {
+            return Ok(PollOutcome::Cancelled(cancel_response(client, response_id).await?));
+        }
+
+        let response = retrieve_response(client, response_id, None).await?;
+        if !matches!(response.status, Some(ResponseStatus::Queued | ResponseStatus::InProgress)) {
+            return Ok(PollOutcome::Finished(response));
+        }
+
+        let mut delay = interval;
+        if let Some(timeout) = options.timeout {
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if remaining.is_zero() {
+                return Ok(PollOutcome::TimedOut(response));
+            }
+            delay = delay.min(remaining);
+        }
+        match &options.cancellation {
+            Some(token) => {
+                if let Either::Right(_) = select(pin!(tokio::time::sleep(delay)), pin!(token.cancelled())).await {
+                    return Ok(PollOutcome::Cancelled(cancel_response(client, response_id).await?));
+                }
+            }
+            None => tokio::time::sleep(delay).await,
+        }
+        interval = next_interval(interval, &options);
+    }
+}
+
+/// The delay after `interval`, grown by the multiplier and capped at `max_interval`. A
+/// multiplier that is negative, NaN or overflows leaves the delay at `max_interval`.
+fn next_interval(interval: Duration, options: &PollOptions) -> Duration {
+    Duration::try_from_secs_f64(interval.as_secs_f64() * options.multiplier)
+        .map_or(options.max_interval, |next| next.min(options.max_interval))
+}
@@ -0,0 +1,124 @@
+// src/client/batches.rs
+
+//! Batch API functions, plus helpers for writing batch input files and reading batch
+//! output files.
+
+use super::files::create_file;
+use super::{execute_request, execute_request_with_body, Client, Result};
+use crate::models::batches::{
+    Batch, BatchCreateParams, BatchListParams, BatchOutputLine, BatchRequest, BatchRequestBody,
+    BatchRequestError, BatchResult,
+};
+use crate::models::files::{FileCreateParams, FileObject, FilePurpose};
+use crate::models::responses::CursorPage;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Creates and executes a batch from an uploaded file of requests.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/batch/create)
+pub async fn create_batch(client: &Client, params: BatchCreateParams) -> Result<Batch> {
+    execute_request_with_body(client, Method::POST, "/batches", Some(params), None).await
+}
+
+/// Retrieves a batch.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/batch/retrieve)
+pub async fn retrieve_batch(client: &Client, batch_id: &str) -> Result<Batch> {
+    let path = format!("/batches/{batch_id}");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Cancels an in-progress batch.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/batch/cancel)
+pub async fn cancel_batch(client: &Client, batch_id: &str) -> Result<Batch> {
+    let path = format!("/batches/{batch_id}/cancel");
+    execute_request_with_body(client, Method::POST, &path, None::<()>, None).await
+}
+
+/// Lists your organization's batches.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/batch/list)
+pub async fn list_batches(client: &Client, params: Option<BatchListParams>) -> Result<CursorPage<Batch>> {
+    let url = format!("{}{}", client.base_url, "/batches");
+    let rb = client.http_client.get(&url).query(&params);
+    execute_request(client, rb, None).await
+}
+
+/// Writes requests as a JSONL batch input file, one line per request.
+pub fn to_jsonl<B: BatchRequestBody>(requests: &[BatchRequest<B>]) -> Result<String> {
+    let mut jsonl = String::new();
+    for request in requests {
+        jsonl.push_str(&serde_json::to_string(request)?);
+        jsonl.push('\n');
+    }
+    Ok(jsonl)
+}
+
+/// Wraps request bodies as batch input lines with the custom IDs `request-0`,
+/// `request-1`, ... in input order.
+pub fn batch_requests<B: BatchRequestBody>(bodies: Vec<B>) -> Vec<BatchRequest<B>> {
+    bodies
+        .into_iter()
+        .enumerate()
+        .map(|(index, body)| BatchRequest::new(format!("request-{index}"), body))
+        .collect()
+}
+
+/// Uploads requests as a batch input file, ready to be passed to [`create_batch`].
+pub async fn upload_batch_requests<B: BatchRequestBody>(
+    client: &Client,
+    filename: &str,
+    requests: &[BatchRequest<B>],
+) -> Result<FileObject> {
+    let params = FileCreateParams {
+        file: to_jsonl(requests)?.into_bytes(),
+        filename: filename.to_string(),
+        purpose: FilePurpose::Batch,
+    };
+    create_file(client, params).await
+}
+
+/// Reads a batch output (or error) file into typed results, in file order.
+///
+/// Lines that failed are returned as [`BatchRequestError`]s; a line that cannot be
+/// parsed at all fails the whole call.
+pub fn parse_batch_output<T: DeserializeOwned>(jsonl: &str) -> Result<Vec<BatchResult<T>>> {
+    jsonl
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_batch_output_line(serde_json::from_str(line)?))
+        .collect()
+}
+
+fn parse_batch_output_line<T: DeserializeOwned>(line: BatchOutputLine) -> Result<BatchResult<T>> {
+    let result = match (line.response, line.error) {
+        (Some(response), None) if (200..300).contains(&response.status_code) => {
+            Ok(serde_json::from_value(response.body)?)
+        }
+        (Some(response), error) => {
+            let body_error = response.body.get("error");
+            let field = |name: &str| {
+                body_error
+                    .and_then(|e| e.get(name))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            };
+            Err(BatchRequestError {
+                status_code: Some(response.status_code),
+                code: field("code").or_else(|| error.as_ref().and_then(|e| e.code.clone())),
+                message: field("message")
+                    .or_else(|| error.map(|e| e.message))
+                    .unwrap_or_else(|| response.body.to_string()),
+            })
+        }
+        (None, Some(error)) => Err(error),
+        (None, None) => Err(BatchRequestError {
+            status_code: None,
+            code: None,
+            message: "Batch output line has neither a response nor an error".to_string(),
+        }),
+    };
+    Ok(BatchResult { custom_id: line.custom_id, result })
+}
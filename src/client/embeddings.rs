@@ -0,0 +1,61 @@
+// src/client/embeddings.rs
+
+//! Embeddings API functions.
+
+use super::{execute_request_with_body, Client, Result};
+use crate::models::embeddings::{CreateEmbeddingResponse, EmbeddingCreateParams};
+use reqwest::Method;
+
+/// The maximum number of inputs the API accepts in a single embedding request.
+pub const MAX_EMBEDDING_INPUTS: usize = 2048;
+
+/// Creates an embedding vector for each input.
+///
+/// Inputs beyond [`MAX_EMBEDDING_INPUTS`] are split across several requests; the
+/// embeddings are returned in input order with their `index` relative to the full input,
+/// and the usage of all requests is summed.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/embeddings/create)
+pub async fn create_embedding(client: &Client, params: EmbeddingCreateParams) -> Result<CreateEmbeddingResponse> {
+    create_embedding_batched(client, params, MAX_EMBEDDING_INPUTS).await
+}
+
+/// Like [`create_embedding`], but sends at most `batch_size` inputs per request. Useful to
+/// stay under the per-request token limit when individual inputs are long.
+pub async fn create_embedding_batched(
+    client: &Client,
+    params: EmbeddingCreateParams,
+    batch_size: usize,
+) -> Result<CreateEmbeddingResponse> {
+    let EmbeddingCreateParams { input, model, dimensions, encoding_format, user } = params;
+    let mut chunks = input.into_chunks(batch_size.max(1));
+    let first = chunks.remove(0);
+    let params_for = |input| EmbeddingCreateParams {
+        input,
+        model: model.clone(),
+        dimensions,
+        encoding_format,
+        user: user.clone(),
+    };
+
+    let mut response = send_embedding_request(client, params_for(first)).await?;
+    for chunk in chunks {
+        let offset = response.data.len();
+        let next = send_embedding_request(client, params_for(chunk)).await?;
+        response.usage.prompt_tokens += next.usage.prompt_tokens;
+        response.usage.total_tokens += next.usage.total_tokens;
+        response.data.extend(next.data.into_iter().map(|mut embedding| {
+            embedding.index += offset;
+            embedding
+        }));
+    }
+    Ok(response)
+}
+
+async fn send_embedding_request(client: &Client, params: EmbeddingCreateParams) -> Result<CreateEmbeddingResponse> {
+    let scope = client.rate_limit_scope(Some(&params.model), &params, None);
+    let mut response: CreateEmbeddingResponse =
+        execute_request_with_body(client, Method::POST, "/embeddings", Some(params), scope).await?;
+    response.data.sort_by_key(|embedding| embedding.index);
+    Ok(response)
+}
@@ -0,0 +1,108 @@
+// src/client/files.rs
+
+//! Files API functions.
+
+use super::{error_from_response, execute_request, execute_request_with_body, read_body, send_request, Client, Result};
+use crate::models::files::{FileCreateParams, FileDeleted, FileListParams, FileObject, FilePurpose};
+use crate::models::responses::CursorPage;
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, Method};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+/// Uploads a file that can be used across various endpoints.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/create)
+pub async fn create_file(client: &Client, params: FileCreateParams) -> Result<FileObject> {
+    let part = Part::bytes(params.file).file_name(params.filename);
+    upload_part(client, part, params.purpose).await
+}
+
+/// Uploads a file by streaming it from `reader`, without buffering it in memory.
+///
+/// Pass the file size as `length` when known so the upload is sent with a
+/// `Content-Length`; otherwise it is sent chunked.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/create)
+pub async fn create_file_from_reader<R>(
+    client: &Client,
+    reader: R,
+    length: Option<u64>,
+    filename: &str,
+    purpose: FilePurpose,
+) -> Result<FileObject>
+where
+    R: AsyncRead + Send + Sync + 'static,
+{
+    let body = Body::wrap_stream(ReaderStream::new(reader));
+    let part = match length {
+        Some(length) => Part::stream_with_length(body, length),
+        None => Part::stream(body),
+    }
+    .file_name(filename.to_string());
+    upload_part(client, part, purpose).await
+}
+
+async fn upload_part(client: &Client, part: Part, purpose: FilePurpose) -> Result<FileObject> {
+    let form = Form::new().text("purpose", purpose.as_str()).part("file", part);
+    let rb = client.http_client
+        .post(format!("{}{}", client.base_url, "/files"))
+        .multipart(form);
+    execute_request(client, rb, None).await
+}
+
+/// Returns a list of files.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/list)
+pub async fn list_files(client: &Client, params: Option<FileListParams>) -> Result<CursorPage<FileObject>> {
+    let url = format!("{}{}", client.base_url, "/files");
+    let rb = client.http_client.get(&url).query(&params);
+    execute_request(client, rb, None).await
+}
+
+/// Returns information about a specific file.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/retrieve)
+pub async fn retrieve_file(client: &Client, file_id: &str) -> Result<FileObject> {
+    let path = format!("/files/{file_id}");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Deletes a file.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/delete)
+pub async fn delete_file(client: &Client, file_id: &str) -> Result<FileDeleted> {
+    let path = format!("/files/{file_id}");
+    execute_request_with_body(client, Method::DELETE, &path, None::<()>, None).await
+}
+
+/// Returns the contents of the specified file.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+pub async fn retrieve_file_content(client: &Client, file_id: &str) -> Result<Bytes> {
+    let response = send_content_request(client, file_id).await?;
+    read_body(response).await
+}
+
+/// Returns the contents of the specified file as a stream of chunks.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+pub async fn retrieve_file_content_stream(
+    client: &Client,
+    file_id: &str,
+) -> Result<impl Stream<Item = Result<Bytes>>> {
+    let response = send_content_request(client, file_id).await?;
+    Ok(response.bytes_stream().map_err(Into::into))
+}
+
+async fn send_content_request(client: &Client, file_id: &str) -> Result<reqwest::Response> {
+    let url = format!("{}/files/{file_id}/content", client.base_url);
+    let response = send_request(client, client.http_client.get(&url), None).await?;
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(error_from_response(response).await)
+    }
+}
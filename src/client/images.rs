@@ -0,0 +1,119 @@
+// src/client/images.rs
+
+//! Images API functions, and a helper to save generated images to disk.
+
+use super::{execute_request, execute_request_with_body, Client, Result};
+use crate::models::images::{
+    image_extension, GeneratedImages, ImageEditParams, ImageGenerateParams, ImageUpload, ImageVariationParams,
+    ImagesResponse,
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use reqwest::multipart::{Form, Part};
+use reqwest::Method;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Creates images from a prompt.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/images/create)
+pub async fn create_image(client: &Client, params: ImageGenerateParams) -> Result<ImagesResponse> {
+    execute_request_with_body(client, Method::POST, "/images/generations", Some(params), None).await
+}
+
+/// Edits or extends one or more images from a prompt, optionally restricted to the
+/// transparent areas of a mask.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/images/createEdit)
+pub async fn create_image_edit(client: &Client, params: ImageEditParams) -> Result<ImagesResponse> {
+    let mut form = Form::new().text("prompt", params.prompt);
+    // A single image is sent as `image`; several as the `image[]` array.
+    let field = if params.images.len() == 1 { "image" } else { "image[]" };
+    for image in params.images {
+        form = form.part(field, image_part(image));
+    }
+    if let Some(mask) = params.mask {
+        form = form.part("mask", image_part(mask));
+    }
+    let fields = [
+        ("model", params.model),
+        ("n", params.n.map(|n| n.to_string())),
+        ("size", params.size),
+        ("quality", params.quality),
+        ("background", params.background),
+        ("output_format", params.output_format),
+        ("output_compression", params.output_compression.map(|c| c.to_string())),
+        ("input_fidelity", params.input_fidelity),
+        ("response_format", params.response_format.map(|f| f.as_str().to_string())),
+        ("user", params.user),
+    ];
+    send_image_form(client, "/images/edits", with_text_fields(form, fields)).await
+}
+
+/// Creates variations of an image.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/images/createVariation)
+pub async fn create_image_variation(client: &Client, params: ImageVariationParams) -> Result<ImagesResponse> {
+    let form = Form::new().part("image", image_part(params.image));
+    let fields = [
+        ("model", params.model),
+        ("n", params.n.map(|n| n.to_string())),
+        ("size", params.size),
+        ("response_format", params.response_format.map(|f| f.as_str().to_string())),
+        ("user", params.user),
+    ];
+    send_image_form(client, "/images/variations", with_text_fields(form, fields)).await
+}
+
+/// Decodes the base64 images of an [`ImagesResponse`], a
+/// [`Response`](crate::models::responses::Response) with image generation calls, or a
+/// partial image event, and writes them to `dir` as `{prefix}-{index}.{ext}`.
+///
+/// The extension is detected from the image data. Returns the written paths in order.
+/// Uses blocking file I/O.
+pub fn save_images(source: &impl GeneratedImages, dir: impl AsRef<Path>, prefix: &str) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+    source
+        .base64_images()
+        .into_iter()
+        .enumerate()
+        .map(|(index, encoded)| {
+            let data = STANDARD
+                .decode(encoded)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let path = dir.join(format!("{prefix}-{index}.{}", image_extension(&data)));
+            std::fs::write(&path, data)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+fn image_part(image: ImageUpload) -> Part {
+    let mime = match image_extension(&image.data) {
+        "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        _ => "image/png",
+    };
+    Part::bytes(image.data)
+        .file_name(image.filename)
+        .mime_str(mime)
+        .expect("static mime types are valid")
+}
+
+fn with_text_fields<const N: usize>(mut form: Form, fields: [(&'static str, Option<String>); N]) -> Form {
+    for (name, value) in fields {
+        if let Some(value) = value {
+            form = form.text(name, value);
+        }
+    }
+    form
+}
+
+async fn send_image_form(client: &Client, path: &str, form: Form) -> Result<ImagesResponse> {
+    let rb = client.http_client
+        .post(format!("{}{}", client.base_url, path))
+        .multipart(form);
+    execute_request(client, rb, None).await
+}
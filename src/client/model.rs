@@ -0,0 +1,31 @@
+// src/client/model.rs
+
+//! Models API functions.
+
+use super::{execute_request_with_body, Client, Result};
+use crate::models::model::{Model, ModelDeleted};
+use crate::models::responses::CursorPage;
+use reqwest::Method;
+
+/// Lists the currently available models.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/models/list)
+pub async fn list_models(client: &Client) -> Result<CursorPage<Model>> {
+    execute_request_with_body(client, Method::GET, "/models", None::<()>, None).await
+}
+
+/// Retrieves a model instance.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/models/retrieve)
+pub async fn retrieve_model(client: &Client, model: &str) -> Result<Model> {
+    let path = format!("/models/{model}");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Deletes a fine-tuned model. You must have the Owner role in your organization.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/models/delete)
+pub async fn delete_model(client: &Client, model: &str) -> Result<ModelDeleted> {
+    let path = format!("/models/{model}");
+    execute_request_with_body(client, Method::DELETE, &path, None::<()>, None).await
+}
@@ -0,0 +1,14 @@
+// src/client/moderations.rs
+
+//! Moderations API functions.
+
+use super::{execute_request_with_body, Client, Result};
+use crate::models::moderations::{ModerationCreateParams, ModerationCreateResponse};
+use reqwest::Method;
+
+/// Classifies whether text and images are potentially harmful.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/moderations/create)
+pub async fn create_moderation(client: &Client, params: ModerationCreateParams) -> Result<ModerationCreateResponse> {
+    execute_request_with_body(client, Method::POST, "/moderations", Some(params), None).await
+}
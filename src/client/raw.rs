@@ -0,0 +1,120 @@
+// src/client/raw.rs
+
+//! Access to the raw HTTP response behind a typed result.
+//!
+//! Wrap any endpoint call in [`with_raw_response`] to get its typed result together with
+//! the status, headers, latency and body bytes of the HTTP response it was built from:
+//!
+//! ```no_run
+//! # async fn example(client: &sh_openai_responses::client::Client) -> sh_openai_responses::client::Result<()> {
+//! use sh_openai_responses::client::{raw::with_raw_response, retrieve_response};
+//!
+//! let response = with_raw_response(retrieve_response(client, "resp_123", None)).await?;
+//! println!("{:?} in {:?}", response.raw.request_id(), response.raw.latency);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{Error, Result};
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+tokio::task_local! {
+    static CAPTURE: Arc<Mutex<Option<Captured>>>;
+}
+
+/// The HTTP response a typed result was deserialized from.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// Time from sending the request to receiving the full body (or, for streaming
+    /// endpoints, the headers). Includes time queued behind the client's rate limiter.
+    pub latency: Duration,
+    /// The response body. Empty for streaming endpoints, whose body is consumed by the stream,
+    /// unless the stream was replayed from the cache.
+    pub body: Bytes,
+}
+
+impl RawResponse {
+    /// The `x-request-id` header, for correlating with OpenAI support.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers.get("x-request-id")?.to_str().ok()
+    }
+
+    /// The server-side processing time from the `openai-processing-ms` header.
+    pub fn processing_time(&self) -> Option<Duration> {
+        let millis = self.headers.get("openai-processing-ms")?.to_str().ok()?.parse().ok()?;
+        Some(Duration::from_millis(millis))
+    }
+}
+
+/// A typed result together with the raw response it came from.
+#[derive(Debug, Clone)]
+pub struct WithRawResponse<T> {
+    pub data: T,
+    pub raw: RawResponse,
+}
+
+/// Runs an endpoint call and returns its result along with the raw HTTP response.
+///
+/// Calls that send several requests (such as moderated responses, batched embeddings or
+/// polling helpers) report the last response received. Calls answered from the client's
+/// cache report a synthetic `200` response with an `x-cache: hit` header.
+pub async fn with_raw_response<T, F>(request: F) -> Result<WithRawResponse<T>>
+where
+    F: Future<Output = Result<T>>,
+{
+    let slot = Arc::new(Mutex::new(None));
+    let data = CAPTURE.scope(slot.clone(), request).await?;
+    let captured = slot.lock().unwrap().take();
+    match captured {
+        Some(captured) => Ok(WithRawResponse { data, raw: captured.raw }),
+        None => Err(Error::UnexpectedResponse("No HTTP response was captured for the request".into())),
+    }
+}
+
+#[derive(Debug)]
+struct Captured {
+    sent_at: Instant,
+    raw: RawResponse,
+}
+
+/// Records the status and headers of a response, when called under [`with_raw_response`].
+pub(super) fn record_head(sent_at: Instant, response: &reqwest::Response) {
+    let _ = CAPTURE.try_with(|slot| {
+        let raw = RawResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            latency: sent_at.elapsed(),
+            body: Bytes::new(),
+        };
+        *slot.lock().unwrap() = Some(Captured { sent_at, raw });
+    });
+}
+
+/// Records a response served from the client's cache: status `200`, an `x-cache: hit`
+/// header and the cached JSON as the body.
+pub(super) fn record_cache_hit(body: Bytes) {
+    let _ = CAPTURE.try_with(|slot| {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-cache", HeaderValue::from_static("hit"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let raw = RawResponse { status: StatusCode::OK, headers, latency: Duration::ZERO, body };
+        *slot.lock().unwrap() = Some(Captured { sent_at: Instant::now(), raw });
+    });
+}
+
+/// Records the body of the last recorded response.
+pub(super) fn record_body(body: &Bytes) {
+    let _ = CAPTURE.try_with(|slot| {
+        if let Some(captured) = slot.lock().unwrap().as_mut() {
+            captured.raw.latency = captured.sent_at.elapsed();
+            captured.raw.body = body.clone();
+        }
+    });
+}
@@ -0,0 +1,287 @@
+// src/client/vector_stores.rs
+
+//! Vector Stores API functions, including file attachment, file batches, status
+//! polling and search.
+
+use super::{execute_request, execute_request_with_body, Client, Error, Result};
+use crate::models::responses::CursorPage;
+use crate::models::vector_stores::{
+    VectorStore, VectorStoreCreateParams, VectorStoreDeleted, VectorStoreFile,
+    VectorStoreFileBatch, VectorStoreFileBatchCreateParams, VectorStoreFileContent,
+    VectorStoreFileCreateParams, VectorStoreFileDeleted, VectorStoreFileListParams,
+    VectorStoreFileStatus, VectorStoreFileUpdateParams, VectorStoreListParams,
+    VectorStoreSearchParams, VectorStoreSearchResultsPage, VectorStoreUpdateParams,
+};
+use reqwest::Method;
+use std::time::{Duration, Instant};
+
+/// The default interval between status checks while polling.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+//=======================================================================================
+// Vector Stores
+//=======================================================================================
+
+/// Creates a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores/create)
+pub async fn create_vector_store(client: &Client, params: VectorStoreCreateParams) -> Result<VectorStore> {
+    execute_request_with_body(client, Method::POST, "/vector_stores", Some(params), None).await
+}
+
+/// Retrieves a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores/retrieve)
+pub async fn retrieve_vector_store(client: &Client, vector_store_id: &str) -> Result<VectorStore> {
+    let path = format!("/vector_stores/{vector_store_id}");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Modifies a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores/modify)
+pub async fn update_vector_store(
+    client: &Client,
+    vector_store_id: &str,
+    params: VectorStoreUpdateParams,
+) -> Result<VectorStore> {
+    let path = format!("/vector_stores/{vector_store_id}");
+    execute_request_with_body(client, Method::POST, &path, Some(params), None).await
+}
+
+/// Returns a list of vector stores.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores/list)
+pub async fn list_vector_stores(
+    client: &Client,
+    params: Option<VectorStoreListParams>,
+) -> Result<CursorPage<VectorStore>> {
+    let url = format!("{}{}", client.base_url, "/vector_stores");
+    let rb = client.http_client.get(&url).query(&params);
+    execute_request(client, rb, None).await
+}
+
+/// Deletes a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores/delete)
+pub async fn delete_vector_store(client: &Client, vector_store_id: &str) -> Result<VectorStoreDeleted> {
+    let path = format!("/vector_stores/{vector_store_id}");
+    execute_request_with_body(client, Method::DELETE, &path, None::<()>, None).await
+}
+
+/// Searches a vector store for chunks relevant to a query, optionally filtered by file
+/// attributes.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores/search)
+pub async fn search_vector_store(
+    client: &Client,
+    vector_store_id: &str,
+    params: VectorStoreSearchParams,
+) -> Result<VectorStoreSearchResultsPage> {
+    let path = format!("/vector_stores/{vector_store_id}/search");
+    execute_request_with_body(client, Method::POST, &path, Some(params), None).await
+}
+
+//=======================================================================================
+// Vector Store Files
+//=======================================================================================
+
+/// Attaches an uploaded file to a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-files/createFile)
+pub async fn create_vector_store_file(
+    client: &Client,
+    vector_store_id: &str,
+    params: VectorStoreFileCreateParams,
+) -> Result<VectorStoreFile> {
+    let path = format!("/vector_stores/{vector_store_id}/files");
+    execute_request_with_body(client, Method::POST, &path, Some(params), None).await
+}
+
+/// Retrieves a vector store file.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-files/getFile)
+pub async fn retrieve_vector_store_file(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+) -> Result<VectorStoreFile> {
+    let path = format!("/vector_stores/{vector_store_id}/files/{file_id}");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Updates the attributes of a vector store file.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-files/updateAttributes)
+pub async fn update_vector_store_file(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+    params: VectorStoreFileUpdateParams,
+) -> Result<VectorStoreFile> {
+    let path = format!("/vector_stores/{vector_store_id}/files/{file_id}");
+    execute_request_with_body(client, Method::POST, &path, Some(params), None).await
+}
+
+/// Returns a list of the files attached to a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-files/listFiles)
+pub async fn list_vector_store_files(
+    client: &Client,
+    vector_store_id: &str,
+    params: Option<VectorStoreFileListParams>,
+) -> Result<CursorPage<VectorStoreFile>> {
+    let url = format!("{}/vector_stores/{vector_store_id}/files", client.base_url);
+    let rb = client.http_client.get(&url).query(&params);
+    execute_request(client, rb, None).await
+}
+
+/// Detaches a file from a vector store. The file itself is not deleted.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-files/deleteFile)
+pub async fn delete_vector_store_file(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+) -> Result<VectorStoreFileDeleted> {
+    let path = format!("/vector_stores/{vector_store_id}/files/{file_id}");
+    execute_request_with_body(client, Method::DELETE, &path, None::<()>, None).await
+}
+
+/// Retrieves the parsed contents of a vector store file.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-files/getContent)
+pub async fn retrieve_vector_store_file_content(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+) -> Result<CursorPage<VectorStoreFileContent>> {
+    let path = format!("/vector_stores/{vector_store_id}/files/{file_id}/content");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Polls a vector store file every `interval` until it is no longer in progress.
+///
+/// Fails with [`Error::UnexpectedResponse`] if `timeout` elapses first.
+pub async fn poll_vector_store_file(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> Result<VectorStoreFile> {
+    let started = Instant::now();
+    loop {
+        let file = retrieve_vector_store_file(client, vector_store_id, file_id).await?;
+        if file.status != VectorStoreFileStatus::InProgress {
+            return Ok(file);
+        }
+        wait_or_time_out(started, interval, timeout, file_id).await?;
+    }
+}
+
+//=======================================================================================
+// Vector Store File Batches
+//=======================================================================================
+
+/// Attaches a batch of uploaded files to a vector store.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/createBatch)
+pub async fn create_vector_store_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    params: VectorStoreFileBatchCreateParams,
+) -> Result<VectorStoreFileBatch> {
+    let path = format!("/vector_stores/{vector_store_id}/file_batches");
+    execute_request_with_body(client, Method::POST, &path, Some(params), None).await
+}
+
+/// Retrieves a vector store file batch.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/getBatch)
+pub async fn retrieve_vector_store_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+) -> Result<VectorStoreFileBatch> {
+    let path = format!("/vector_stores/{vector_store_id}/file_batches/{batch_id}");
+    execute_request_with_body(client, Method::GET, &path, None::<()>, None).await
+}
+
+/// Cancels a vector store file batch.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/cancelBatch)
+pub async fn cancel_vector_store_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+) -> Result<VectorStoreFileBatch> {
+    let path = format!("/vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel");
+    execute_request_with_body(client, Method::POST, &path, None::<()>, None).await
+}
+
+/// Returns a list of the files in a vector store file batch.
+///
+/// [API Documentation](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/listBatchFiles)
+pub async fn list_vector_store_file_batch_files(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+    params: Option<VectorStoreFileListParams>,
+) -> Result<CursorPage<VectorStoreFile>> {
+    let url = format!(
+        "{}/vector_stores/{vector_store_id}/file_batches/{batch_id}/files",
+        client.base_url
+    );
+    let rb = client.http_client.get(&url).query(&params);
+    execute_request(client, rb, None).await
+}
+
+/// Polls a vector store file batch every `interval` until it is no longer in progress.
+///
+/// Fails with [`Error::UnexpectedResponse`] if `timeout` elapses first.
+pub async fn poll_vector_store_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> Result<VectorStoreFileBatch> {
+    let started = Instant::now();
+    loop {
+        let batch = retrieve_vector_store_file_batch(client, vector_store_id, batch_id).await?;
+        if batch.status != VectorStoreFileStatus::InProgress {
+            return Ok(batch);
+        }
+        wait_or_time_out(started, interval, timeout, batch_id).await?;
+    }
+}
+
+/// Attaches a batch of uploaded files to a vector store and waits for them to finish
+/// processing.
+pub async fn create_vector_store_file_batch_and_poll(
+    client: &Client,
+    vector_store_id: &str,
+    params: VectorStoreFileBatchCreateParams,
+    timeout: Option<Duration>,
+) -> Result<VectorStoreFileBatch> {
+    let batch = create_vector_store_file_batch(client, vector_store_id, params).await?;
+    poll_vector_store_file_batch(client, vector_store_id, &batch.id, DEFAULT_POLL_INTERVAL, timeout).await
+}
+
+async fn wait_or_time_out(
+    started: Instant,
+    interval: Duration,
+    timeout: Option<Duration>,
+    id: &str,
+) -> Result<()> {
+    if let Some(timeout) = timeout
+        && started.elapsed() + interval > timeout
+    {
+        return Err(Error::UnexpectedResponse(format!(
+            "Timed out after {timeout:?} waiting for '{id}' to finish processing"
+        )));
+    }
+    tokio::time::sleep(interval).await;
+    Ok(())
+}
@@ -0,0 +1,404 @@
+// src/cost.rs
+
+//! Converts reported token usage into spend, and aggregates that spend per tag.
+//!
+//! Prices are expressed in USD per one million tokens and are keyed by model and
+//! [`ServiceTier`]. The built-in [`PricingTable`] can be overridden entry by entry, or
+//! replaced wholesale by deserializing a table from configuration.
+
+use crate::models::responses::{
+    ChatCompletion, Metadata, Response, ResponseCompletedEvent, ServiceTier,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign};
+use std::sync::Mutex;
+
+/// The tag spend is recorded under when no tag can be derived from a request.
+pub const UNTAGGED: &str = "untagged";
+
+const TOKENS_PER_UNIT: f64 = 1_000_000.0;
+
+//=======================================================================================
+// Pricing
+//=======================================================================================
+
+/// The price of a model on a single service tier, in USD per one million tokens.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ModelPricing {
+    /// The price of uncached input tokens.
+    pub input: f64,
+    /// The price of input tokens served from the prompt cache. Falls back to `input`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_input: Option<f64>,
+    /// The price of output tokens, including reasoning tokens.
+    pub output: f64,
+}
+
+impl ModelPricing {
+    /// Creates a new pricing entry.
+    pub fn new(input: f64, cached_input: Option<f64>, output: f64) -> Self {
+        Self { input, cached_input, output }
+    }
+
+    /// Computes the cost of the given token counts.
+    pub fn cost(&self, input_tokens: i64, cached_tokens: i64, output_tokens: i64) -> Cost {
+        let cached_tokens = cached_tokens.clamp(0, input_tokens.max(0));
+        let uncached_tokens = input_tokens.max(0) - cached_tokens;
+        Cost {
+            input: uncached_tokens as f64 * self.input / TOKENS_PER_UNIT,
+            cached_input: cached_tokens as f64 * self.cached_input.unwrap_or(self.input)
+                / TOKENS_PER_UNIT,
+            output: output_tokens.max(0) as f64 * self.output / TOKENS_PER_UNIT,
+        }
+    }
+}
+
+/// A table of model prices keyed by model ID and service tier.
+///
+/// Model IDs are matched exactly first. A dated snapshot such as `gpt-4.1-2025-04-14`, or
+/// an alias such as `gpt-5-chat-latest`, then falls back to its base model's entry. Other
+/// variants (`o1-pro`, `gpt-4o-audio-preview`) are priced only by their own entries. A
+/// tier without its own entry is priced as [`ServiceTier::Default`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(transparent)]
+pub struct PricingTable {
+    models: HashMap<String, HashMap<ServiceTier, ModelPricing>>,
+}
+
+impl PricingTable {
+    /// Creates an empty pricing table.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Creates a pricing table with the built-in list prices.
+    pub fn builtin() -> Self {
+        use ServiceTier::{Default, Flex, Priority};
+
+        let entries: &[(&str, ServiceTier, f64, f64, f64)] = &[
+            ("gpt-5", Default, 1.25, 0.125, 10.00),
+            ("gpt-5", Flex, 0.625, 0.0625, 5.00),
+            ("gpt-5", Priority, 2.50, 0.25, 20.00),
+            ("gpt-5-mini", Default, 0.25, 0.025, 2.00),
+            ("gpt-5-mini", Flex, 0.125, 0.0125, 1.00),
+            ("gpt-5-mini", Priority, 0.45, 0.045, 3.60),
+            ("gpt-5-nano", Default, 0.05, 0.005, 0.40),
+            ("gpt-5-nano", Flex, 0.025, 0.0025, 0.20),
+            ("gpt-4.1", Default, 2.00, 0.50, 8.00),
+            ("gpt-4.1", Priority, 3.50, 0.875, 14.00),
+            ("gpt-4.1-mini", Default, 0.40, 0.10, 1.60),
+            ("gpt-4.1-mini", Priority, 0.70, 0.175, 2.80),
+            ("gpt-4.1-nano", Default, 0.10, 0.025, 0.40),
+            ("gpt-4.1-nano", Priority, 0.20, 0.05, 0.80),
+            ("gpt-4o", Default, 2.50, 1.25, 10.00),
+            ("gpt-4o", Priority, 4.25, 2.125, 17.00),
+            ("gpt-4o-mini", Default, 0.15, 0.075, 0.60),
+            ("gpt-4o-mini", Priority, 0.25, 0.125, 1.00),
+            ("o1", Default, 15.00, 7.50, 60.00),
+            ("o1-mini", Default, 1.10, 0.55, 4.40),
+            ("o3", Default, 2.00, 0.50, 8.00),
+            ("o3", Flex, 1.00, 0.25, 4.00),
+            ("o3", Priority, 3.50, 0.875, 14.00),
+            ("o3-mini", Default, 1.10, 0.55, 4.40),
+            ("o4-mini", Default, 1.10, 0.275, 4.40),
+            ("o4-mini", Flex, 0.55, 0.138, 2.20),
+            ("o4-mini", Priority, 2.00, 0.50, 8.00),
+        ];
+
+        let mut table = Self::empty();
+        for &(model, tier, input, cached_input, output) in entries {
+            table.set(model, tier, ModelPricing::new(input, Some(cached_input), output));
+        }
+        // o1-pro has no cached input discount.
+        table.set("o1-pro", Default, ModelPricing::new(150.00, None, 600.00));
+        table
+    }
+
+    /// Sets (or overrides) the price of a model on a service tier.
+    pub fn set(&mut self, model: impl Into<String>, tier: ServiceTier, pricing: ModelPricing) {
+        self.models.entry(model.into()).or_default().insert(tier, pricing);
+    }
+
+    /// Builder-style variant of [`PricingTable::set`].
+    pub fn with(mut self, model: impl Into<String>, tier: ServiceTier, pricing: ModelPricing) -> Self {
+        self.set(model, tier, pricing);
+        self
+    }
+
+    /// Overlays every entry of `overrides` on top of this table.
+    pub fn merge(&mut self, overrides: PricingTable) {
+        for (model, tiers) in overrides.models {
+            self.models.entry(model).or_default().extend(tiers);
+        }
+    }
+
+    /// Looks up the price of a model on a service tier.
+    ///
+    /// `None` and [`ServiceTier::Auto`] resolve to [`ServiceTier::Default`].
+    pub fn get(&self, model: &str, tier: Option<ServiceTier>) -> Option<&ModelPricing> {
+        let tiers = lookup_model(&self.models, model)?;
+
+        let tier = match tier {
+            None | Some(ServiceTier::Auto) => ServiceTier::Default,
+            Some(tier) => tier,
+        };
+        tiers.get(&tier).or_else(|| tiers.get(&ServiceTier::Default))
+    }
+}
+
+/// Looks up `model` exactly, then, if it is a dated snapshot (`-YYYY-MM-DD`) or a
+/// `-latest` alias, by its base model.
+pub(crate) fn lookup_model<'a, V>(entries: &'a HashMap<String, V>, model: &str) -> Option<&'a V> {
+    entries.get(model).or_else(|| entries.get(snapshot_base(model)?))
+}
+
+/// The base model of a dated snapshot or `-latest` alias, e.g. `gpt-4.1` for
+/// `gpt-4.1-2025-04-14`.
+fn snapshot_base(model: &str) -> Option<&str> {
+    if let Some(base) = model.strip_suffix("-latest") {
+        return Some(base);
+    }
+    let (base, date) = model.split_at_checked(model.len().checked_sub(11)?)?;
+    let date = date.as_bytes();
+    let is_date = date[0] == b'-'
+        && date[5] == b'-'
+        && date[8] == b'-'
+        && [1..5, 6..8, 9..11].into_iter().all(|range| date[range].iter().all(u8::is_ascii_digit));
+    is_date.then_some(base)
+}
+
+//=======================================================================================
+// Cost
+//=======================================================================================
+
+/// The cost of one or more requests, in USD.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct Cost {
+    /// The cost of uncached input tokens.
+    pub input: f64,
+    /// The cost of input tokens served from the prompt cache.
+    pub cached_input: f64,
+    /// The cost of output tokens, including reasoning tokens.
+    pub output: f64,
+}
+
+impl Cost {
+    /// The total cost.
+    pub fn total(&self) -> f64 {
+        self.input + self.cached_input + self.output
+    }
+}
+
+impl Add for Cost {
+    type Output = Cost;
+
+    fn add(self, rhs: Cost) -> Cost {
+        Cost {
+            input: self.input + rhs.input,
+            cached_input: self.cached_input + rhs.cached_input,
+            output: self.output + rhs.output,
+        }
+    }
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, rhs: Cost) {
+        *self = *self + rhs;
+    }
+}
+
+/// Token counts normalized across the Responses and Chat Completions APIs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TokenCounts {
+    pub input_tokens: i64,
+    pub cached_tokens: i64,
+    pub output_tokens: i64,
+    pub reasoning_tokens: i64,
+}
+
+impl Add for TokenCounts {
+    type Output = TokenCounts;
+
+    fn add(self, rhs: TokenCounts) -> TokenCounts {
+        TokenCounts {
+            input_tokens: self.input_tokens + rhs.input_tokens,
+            cached_tokens: self.cached_tokens + rhs.cached_tokens,
+            output_tokens: self.output_tokens + rhs.output_tokens,
+            reasoning_tokens: self.reasoning_tokens + rhs.reasoning_tokens,
+        }
+    }
+}
+
+/// Implemented by API results that report token usage.
+pub trait UsageCost {
+    /// The model that produced the result.
+    fn model(&self) -> &str;
+
+    /// The service tier the request was processed on, if reported.
+    fn service_tier(&self) -> Option<ServiceTier>;
+
+    /// The token usage of the result, if reported.
+    fn token_counts(&self) -> Option<TokenCounts>;
+
+    /// Computes the cost of the result, or `None` if it has no usage or its model is
+    /// missing from `pricing`.
+    fn cost(&self, pricing: &PricingTable) -> Option<Cost> {
+        let tokens = self.token_counts()?;
+        let model_pricing = pricing.get(self.model(), self.service_tier())?;
+        Some(model_pricing.cost(tokens.input_tokens, tokens.cached_tokens, tokens.output_tokens))
+    }
+}
+
+impl UsageCost for Response {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn service_tier(&self) -> Option<ServiceTier> {
+        self.service_tier
+    }
+
+    fn token_counts(&self) -> Option<TokenCounts> {
+        self.usage.as_ref().map(|usage| TokenCounts {
+            input_tokens: usage.input_tokens,
+            cached_tokens: usage.input_tokens_details.cached_tokens,
+            output_tokens: usage.output_tokens,
+            reasoning_tokens: usage.output_tokens_details.reasoning_tokens,
+        })
+    }
+}
+
+impl UsageCost for ResponseCompletedEvent {
+    fn model(&self) -> &str {
+        self.response.model()
+    }
+
+    fn service_tier(&self) -> Option<ServiceTier> {
+        self.response.service_tier()
+    }
+
+    fn token_counts(&self) -> Option<TokenCounts> {
+        self.response.token_counts()
+    }
+}
+
+impl UsageCost for ChatCompletion {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn service_tier(&self) -> Option<ServiceTier> {
+        None
+    }
+
+    fn token_counts(&self) -> Option<TokenCounts> {
+        self.usage.as_ref().map(|usage| TokenCounts {
+            input_tokens: usage.prompt_tokens.into(),
+            cached_tokens: 0,
+            output_tokens: usage.completion_tokens.into(),
+            reasoning_tokens: 0,
+        })
+    }
+}
+
+//=======================================================================================
+// Aggregation
+//=======================================================================================
+
+/// Accumulated usage and spend for a single tag.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TagSpend {
+    /// The number of requests recorded.
+    pub requests: u64,
+    /// The number of recorded requests whose cost could not be determined.
+    pub unpriced_requests: u64,
+    /// The accumulated token usage.
+    pub tokens: TokenCounts,
+    /// The accumulated cost.
+    pub cost: Cost,
+}
+
+/// A thread-safe aggregator of spend per tag.
+///
+/// Attach one to a [`Client`](crate::client::Client) with
+/// [`Client::with_cost_tracker`](crate::client::Client::with_cost_tracker) to record every
+/// completed request automatically. The tag is read from the request `metadata` under
+/// [`CostTracker::with_tag_key`], falling back to [`UNTAGGED`].
+#[derive(Debug)]
+pub struct CostTracker {
+    pricing: PricingTable,
+    tag_key: Option<String>,
+    spend: Mutex<HashMap<String, TagSpend>>,
+}
+
+impl Default for CostTracker {
+    fn default() -> Self {
+        Self::new(PricingTable::builtin())
+    }
+}
+
+impl CostTracker {
+    /// Creates a tracker that prices usage with the given table.
+    pub fn new(pricing: PricingTable) -> Self {
+        Self { pricing, tag_key: None, spend: Mutex::new(HashMap::new()) }
+    }
+
+    /// Sets the request metadata key whose value is used as the tag.
+    pub fn with_tag_key(mut self, key: impl Into<String>) -> Self {
+        self.tag_key = Some(key.into());
+        self
+    }
+
+    /// The pricing table used by this tracker.
+    pub fn pricing(&self) -> &PricingTable {
+        &self.pricing
+    }
+
+    /// Derives the tag for a request from its metadata.
+    pub fn tag_for(&self, metadata: Option<&Metadata>) -> String {
+        self.tag_key
+            .as_ref()
+            .and_then(|key| metadata?.get(key))
+            .cloned()
+            .unwrap_or_else(|| UNTAGGED.to_string())
+    }
+
+    /// Records the usage of a result under `tag` and returns its cost, if known.
+    pub fn record(&self, tag: &str, result: &impl UsageCost) -> Option<Cost> {
+        let cost = result.cost(&self.pricing);
+        let mut spend = self.spend.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = spend.entry(tag.to_string()).or_default();
+        entry.requests += 1;
+        entry.tokens = entry.tokens + result.token_counts().unwrap_or_default();
+        match cost {
+            Some(cost) => entry.cost += cost,
+            None => entry.unpriced_requests += 1,
+        }
+        cost
+    }
+
+    /// Returns a snapshot of the spend per tag.
+    pub fn report(&self) -> HashMap<String, TagSpend> {
+        self.spend.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Returns the spend recorded under a single tag.
+    pub fn spend_for(&self, tag: &str) -> Option<TagSpend> {
+        self.spend.lock().unwrap_or_else(|e| e.into_inner()).get(tag).cloned()
+    }
+
+    /// Returns the total cost across all tags.
+    pub fn total(&self) -> Cost {
+        self.spend
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .fold(Cost::default(), |acc, spend| acc + spend.cost)
+    }
+
+    /// Clears all recorded spend.
+    pub fn reset(&self) {
+        self.spend.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
@@ -0,0 +1,74 @@
+// src/interceptor.rs
+
+//! HTTP middleware for the [`Client`](crate::client::Client).
+//!
+//! Every request the client sends, streaming or not, passes through the attached
+//! [`Interceptor`]s: `before_request` hooks run in the order the interceptors were added
+//! and may modify the request or answer it with a canned response, then `after_response`
+//! hooks run in reverse order and may inspect or replace the response. Requests retried
+//! by the rate limiter pass through the chain once per attempt.
+
+use crate::client::Result;
+use futures_util::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Request, Response, StatusCode, Url};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A hook around each HTTP request sent by the client.
+pub trait Interceptor: Send + Sync {
+    /// Called before a request is sent. Modify `request` in place, or return `Some`
+    /// response to skip the network (and any later interceptors) entirely.
+    ///
+    /// The request already carries the client's default headers, including `authorization`.
+    fn before_request<'a>(&'a self, request: &'a mut Request) -> BoxFuture<'a, Result<Option<Response>>> {
+        let _ = request;
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Called with the response to a request, whether it was received or returned by a
+    /// `before_request` hook. Return the response (or a replacement) to continue.
+    fn after_response<'a>(&'a self, request: &'a RequestInfo, response: Response) -> BoxFuture<'a, Result<Response>> {
+        let _ = request;
+        Box::pin(async { Ok(response) })
+    }
+}
+
+/// The request an `after_response` hook is called for, as it was sent.
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// Time from running the first `before_request` hook to receiving the response headers.
+    pub elapsed: Duration,
+}
+
+/// Builds a response for a `before_request` hook to short-circuit with.
+pub fn canned_response(status: StatusCode, headers: HeaderMap, body: impl Into<reqwest::Body>) -> Response {
+    let mut response = http::Response::new(body.into());
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
+
+/// The interceptors attached to a client, in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct InterceptorChain(Vec<Arc<dyn Interceptor>>);
+
+impl InterceptorChain {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub(crate) fn as_slice(&self) -> &[Arc<dyn Interceptor>] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for InterceptorChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InterceptorChain({} interceptors)", self.0.len())
+    }
+}
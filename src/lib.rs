@@ -1,2 +1,13 @@
 pub mod models;
-pub mod client;
\ No newline at end of file
+pub mod client;
+pub mod cost;
+pub mod budget;
+pub mod rate_limit;
+pub mod moderation;
+pub mod capabilities;
+pub mod cache;
+pub mod interceptor;
+pub mod transport;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod reasoning;
\ No newline at end of file
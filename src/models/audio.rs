@@ -0,0 +1,274 @@
+// src/models/audio.rs
+
+use serde::{Deserialize, Serialize};
+
+//=======================================================================================
+// Transcriptions and Translations
+//=======================================================================================
+
+/// The format of a transcription or translation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioResponseFormat {
+    Json,
+    Text,
+    Srt,
+    VerboseJson,
+    Vtt,
+}
+
+impl AudioResponseFormat {
+    /// The format as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioResponseFormat::Json => "json",
+            AudioResponseFormat::Text => "text",
+            AudioResponseFormat::Srt => "srt",
+            AudioResponseFormat::VerboseJson => "verbose_json",
+            AudioResponseFormat::Vtt => "vtt",
+        }
+    }
+
+    /// Whether the API returns the transcript as plain text rather than JSON.
+    pub fn is_plain_text(&self) -> bool {
+        matches!(self, AudioResponseFormat::Text | AudioResponseFormat::Srt | AudioResponseFormat::Vtt)
+    }
+}
+
+/// The granularity of timestamps in a `verbose_json` transcription.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    Word,
+    Segment,
+}
+
+impl TimestampGranularity {
+    /// The granularity as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimestampGranularity::Word => "word",
+            TimestampGranularity::Segment => "segment",
+        }
+    }
+}
+
+/// Parameters for transcribing audio. Sent as a multipart form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptionCreateParams {
+    /// The audio file contents, in one of flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav or webm.
+    pub file: Vec<u8>,
+    /// The name the file is uploaded under. Its extension tells the API the audio format.
+    pub filename: String,
+    /// The ID of the model to use, e.g. `gpt-4o-transcribe` or `whisper-1`.
+    pub model: String,
+    /// The language of the input audio in ISO-639-1 format, e.g. `en`.
+    pub language: Option<String>,
+    /// Text to guide the model's style or continue a previous audio segment.
+    pub prompt: Option<String>,
+    /// The format of the transcript.
+    pub response_format: Option<AudioResponseFormat>,
+    /// The sampling temperature, between 0 and 1.
+    pub temperature: Option<f64>,
+    /// The timestamp granularities to populate. Requires `verbose_json`.
+    pub timestamp_granularities: Option<Vec<TimestampGranularity>>,
+    /// Whether to stream the transcript as it is produced. Not supported by `whisper-1`.
+    pub stream: Option<bool>,
+}
+
+impl TranscriptionCreateParams {
+    /// Creates parameters to transcribe `file` with `model`.
+    pub fn new(file: Vec<u8>, filename: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            file,
+            filename: filename.into(),
+            model: model.into(),
+            language: None,
+            prompt: None,
+            response_format: None,
+            temperature: None,
+            timestamp_granularities: None,
+            stream: None,
+        }
+    }
+}
+
+/// Parameters for translating audio into English. Sent as a multipart form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslationCreateParams {
+    /// The audio file contents, in one of flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav or webm.
+    pub file: Vec<u8>,
+    /// The name the file is uploaded under. Its extension tells the API the audio format.
+    pub filename: String,
+    /// The ID of the model to use. Only `whisper-1` is currently available.
+    pub model: String,
+    /// English text to guide the model's style or continue a previous audio segment.
+    pub prompt: Option<String>,
+    /// The format of the translation.
+    pub response_format: Option<AudioResponseFormat>,
+    /// The sampling temperature, between 0 and 1.
+    pub temperature: Option<f64>,
+}
+
+impl TranslationCreateParams {
+    /// Creates parameters to translate `file` with `model`.
+    pub fn new(file: Vec<u8>, filename: impl Into<String>, model: impl Into<String>) -> Self {
+        Self { file, filename: filename.into(), model: model.into(), prompt: None, response_format: None, temperature: None }
+    }
+}
+
+/// A word with its timestamps.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TranscriptionWord {
+    /// The text content of the word.
+    pub word: String,
+    /// Start time of the word in seconds.
+    pub start: f64,
+    /// End time of the word in seconds.
+    pub end: f64,
+}
+
+/// A segment of transcribed text with its timestamps.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TranscriptionSegment {
+    pub id: i64,
+    /// Seek offset of the segment.
+    pub seek: i64,
+    /// Start time of the segment in seconds.
+    pub start: f64,
+    /// End time of the segment in seconds.
+    pub end: f64,
+    /// The text content of the segment.
+    pub text: String,
+    /// The token IDs of the text content.
+    pub tokens: Vec<i64>,
+    /// The temperature used to generate the segment.
+    pub temperature: f64,
+    /// The average log probability of the segment.
+    pub avg_logprob: f64,
+    /// The compression ratio of the segment.
+    pub compression_ratio: f64,
+    /// The probability of no speech in the segment.
+    pub no_speech_prob: f64,
+}
+
+/// The log probability of a token in a transcript.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TranscriptionLogprob {
+    pub token: String,
+    pub logprob: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<Vec<u8>>,
+}
+
+/// A transcription or translation of input audio.
+///
+/// `json` transcripts only populate `text`; `verbose_json` adds the language, duration
+/// and the requested timestamps. Plain-text formats (`text`, `srt`, `vtt`) are returned
+/// verbatim in `text`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Transcription {
+    /// The transcribed text.
+    pub text: String,
+    /// The language of the input audio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The duration of the input audio in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// Extracted words and their timestamps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub words: Option<Vec<TranscriptionWord>>,
+    /// Segments of the transcribed text and their timestamps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<TranscriptionSegment>>,
+}
+
+/// A translation of input audio into English.
+pub type Translation = Transcription;
+
+/// An event emitted while streaming a transcription.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum TranscriptionStreamEvent {
+    /// Additional transcribed text.
+    #[serde(rename = "transcript.text.delta")]
+    TextDelta {
+        delta: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        logprobs: Option<Vec<TranscriptionLogprob>>,
+    },
+    /// The transcription is complete.
+    #[serde(rename = "transcript.text.done")]
+    TextDone {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        logprobs: Option<Vec<TranscriptionLogprob>>,
+    },
+    /// An event type not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+//=======================================================================================
+// Speech
+//=======================================================================================
+
+/// The voice to use when generating speech.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeechVoice {
+    Alloy,
+    Ash,
+    Ballad,
+    Coral,
+    Echo,
+    Fable,
+    Onyx,
+    Nova,
+    Sage,
+    Shimmer,
+    Verse,
+    /// A voice not known to this crate.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The audio format of generated speech.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeechResponseFormat {
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+    Pcm,
+}
+
+/// Parameters for generating speech from text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeechCreateParams {
+    /// The text to generate audio for, up to 4096 characters.
+    pub input: String,
+    /// The ID of the model to use, e.g. `gpt-4o-mini-tts` or `tts-1`.
+    pub model: String,
+    /// The voice to use.
+    pub voice: SpeechVoice,
+    /// Instructions for the voice, e.g. tone or pacing. Not supported by `tts-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// The audio format. Defaults to `mp3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<SpeechResponseFormat>,
+    /// The speed of the generated audio, between 0.25 and 4.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+}
+
+impl SpeechCreateParams {
+    /// Creates parameters to speak `input` with `model` in `voice`.
+    pub fn new(model: impl Into<String>, input: impl Into<String>, voice: SpeechVoice) -> Self {
+        Self { input: input.into(), model: model.into(), voice, instructions: None, response_format: None, speed: None }
+    }
+}
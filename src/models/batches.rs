@@ -0,0 +1,275 @@
+// src/models/batches.rs
+
+use crate::models::responses::{
+    ChatCompletion, ChatCompletionCreateParams, Metadata, Response, ResponseCreateParams,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The endpoint used for all requests in a batch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BatchEndpoint {
+    #[serde(rename = "/v1/responses")]
+    Responses,
+    #[serde(rename = "/v1/chat/completions")]
+    ChatCompletions,
+    #[serde(rename = "/v1/embeddings")]
+    Embeddings,
+    #[serde(rename = "/v1/completions")]
+    Completions,
+}
+
+impl BatchEndpoint {
+    /// The endpoint path as used in batch input lines.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchEndpoint::Responses => "/v1/responses",
+            BatchEndpoint::ChatCompletions => "/v1/chat/completions",
+            BatchEndpoint::Embeddings => "/v1/embeddings",
+            BatchEndpoint::Completions => "/v1/completions",
+        }
+    }
+}
+
+/// The current status of a batch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    Validating,
+    Failed,
+    InProgress,
+    Finalizing,
+    Completed,
+    Expired,
+    Cancelling,
+    Cancelled,
+}
+
+/// The request counts for different statuses within a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchRequestCounts {
+    /// Number of requests that have been completed successfully.
+    pub completed: u64,
+    /// Number of requests that have failed.
+    pub failed: u64,
+    /// Total number of requests in the batch.
+    pub total: u64,
+}
+
+/// An error that occurred while validating or processing a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchError {
+    /// An error code identifying the error type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The line number of the input file where the error occurred, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u64>,
+    /// A human-readable message providing more details about the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The name of the parameter that caused the error, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+}
+
+/// The errors of a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchErrors {
+    pub data: Vec<BatchError>,
+    /// The object type, which is always `list`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<String>,
+}
+
+/// A batch of asynchronous requests.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Batch {
+    pub id: String,
+    /// The time frame within which the batch should be processed.
+    pub completion_window: String,
+    /// The Unix timestamp (in seconds) for when the batch was created.
+    pub created_at: i64,
+    /// The API endpoint used by the batch.
+    pub endpoint: String,
+    /// The ID of the input file for the batch.
+    pub input_file_id: String,
+    /// The object type, which is always `batch`.
+    pub object: String,
+    /// The current status of the batch.
+    pub status: BatchStatus,
+    /// The Unix timestamp (in seconds) for when the batch was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<i64>,
+    /// The Unix timestamp (in seconds) for when the batch started cancelling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelling_at: Option<i64>,
+    /// The Unix timestamp (in seconds) for when the batch was completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<i64>,
+    /// The ID of the file containing the outputs of requests with errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_file_id: Option<String>,
+    /// The errors of the batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<BatchErrors>,
+    /// The Unix timestamp (in seconds) for when the batch expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired_at: Option<i64>,
+    /// The Unix timestamp (in seconds) for when the batch will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+    /// The Unix timestamp (in seconds) for when the batch failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_at: Option<i64>,
+    /// The Unix timestamp (in seconds) for when the batch started finalizing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalizing_at: Option<i64>,
+    /// The Unix timestamp (in seconds) for when the batch started processing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_progress_at: Option<i64>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The ID of the file containing the outputs of successfully executed requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_file_id: Option<String>,
+    /// The request counts for different statuses within the batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_counts: Option<BatchRequestCounts>,
+}
+
+/// Parameters for creating a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchCreateParams {
+    /// The time frame within which the batch should be processed. Currently only `24h`.
+    pub completion_window: String,
+    /// The endpoint to be used for all requests in the batch.
+    pub endpoint: BatchEndpoint,
+    /// The ID of an uploaded file that contains requests for the new batch.
+    pub input_file_id: String,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl BatchCreateParams {
+    /// Creates parameters for a batch with the default `24h` completion window.
+    pub fn new(input_file_id: impl Into<String>, endpoint: BatchEndpoint) -> Self {
+        Self {
+            completion_window: "24h".to_string(),
+            endpoint,
+            input_file_id: input_file_id.into(),
+            metadata: None,
+        }
+    }
+}
+
+/// Parameters for listing batches.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BatchListParams {
+    /// A cursor for use in pagination; the ID of the last batch of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A limit on the number of objects to be returned, between 1 and 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+//=======================================================================================
+// Batch Input & Output Lines
+//=======================================================================================
+
+/// A request body that can be sent as part of a batch.
+pub trait BatchRequestBody: Serialize {
+    /// The endpoint requests of this type are sent to.
+    const ENDPOINT: BatchEndpoint;
+    /// The type of a successful response body.
+    type Output: DeserializeOwned;
+}
+
+impl BatchRequestBody for ResponseCreateParams {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::Responses;
+    type Output = Response;
+}
+
+impl BatchRequestBody for ChatCompletionCreateParams {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::ChatCompletions;
+    type Output = ChatCompletion;
+}
+
+/// A single line of a batch input file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchRequest<B> {
+    /// A developer-provided ID used to match outputs to inputs. Must be unique per batch.
+    pub custom_id: String,
+    /// The HTTP method of the request. Always `POST`.
+    pub method: String,
+    /// The endpoint path of the request.
+    pub url: String,
+    /// The request body.
+    pub body: B,
+}
+
+impl<B: BatchRequestBody> BatchRequest<B> {
+    /// Creates a batch input line for `body`.
+    pub fn new(custom_id: impl Into<String>, body: B) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            method: "POST".to_string(),
+            url: B::ENDPOINT.as_str().to_string(),
+            body,
+        }
+    }
+}
+
+/// The response recorded for a single line of a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchOutputResponse {
+    /// The HTTP status code of the response.
+    pub status_code: u16,
+    /// A unique identifier for the API request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// The JSON body of the response.
+    pub body: Value,
+}
+
+/// An error recorded for a single line of a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchRequestError {
+    /// The HTTP status code, if the request reached the endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    /// A machine-readable error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// A human-readable error message.
+    pub message: String,
+}
+
+/// A single line of a batch output or error file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchOutputLine {
+    /// The ID of the batch request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The developer-provided ID of the input line.
+    pub custom_id: String,
+    /// The response, if the request was executed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<BatchOutputResponse>,
+    /// The error, if the request could not be executed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<BatchRequestError>,
+}
+
+/// The typed result of a single line of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchResult<T> {
+    /// The developer-provided ID of the input line.
+    pub custom_id: String,
+    /// The typed response body, or the error reported for the line.
+    pub result: std::result::Result<T, BatchRequestError>,
+}
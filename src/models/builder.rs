@@ -0,0 +1,383 @@
+// src/models/builder.rs
+
+//! A fluent builder for [`ResponseCreateParams`], plus constructors for input messages
+//! and content:
+//!
+//! ```
+//! use sh_openai_responses::models::responses::ResponseCreateParams;
+//!
+//! let params = ResponseCreateParams::builder("gpt-4.1")
+//!     .instructions("Answer as a triage nurse.")
+//!     .user_text("What does this rash look like to you?")
+//!     .user_image_url("https://example.com/rash.png")
+//!     .temperature(0.2)
+//!     .build();
+//! ```
+//!
+//! The model is required up front, and `build` is only available once at least one
+//! input item has been added, so an incomplete request does not compile.
+
+use crate::models::responses::{
+    EasyInputMessage, EasyInputMessageContent, ImageDetail, MessageRole, Metadata, Reasoning,
+    ResponseConversationParam, ResponseCreateParams, ResponseIncludable, ResponseInputContent,
+    ResponseInputFile, ResponseInputImage, ResponseInputItem, ResponseInputParam, ResponseInputText,
+    ResponseInstructions, ResponseTextConfig, ServiceTier, Tool, ToolChoice, TruncationStrategy,
+};
+use serde_json::Value;
+use std::marker::PhantomData;
+
+//=======================================================================================
+// Input Content & Messages
+//=======================================================================================
+
+impl ResponseInputContent {
+    /// A text input.
+    pub fn text(text: impl Into<String>) -> Self {
+        ResponseInputContent::InputText(ResponseInputText { text: text.into(), type_field: "input_text".to_string() })
+    }
+
+    /// An image input from a fully qualified URL or a base64 data URL, with `auto` detail.
+    pub fn image_url(url: impl Into<String>) -> Self {
+        ResponseInputContent::InputImage(ResponseInputImage {
+            detail: ImageDetail::Auto,
+            type_field: "input_image".to_string(),
+            file_id: None,
+            image_url: Some(url.into()),
+        })
+    }
+
+    /// An image input from an uploaded file, with `auto` detail.
+    pub fn image_file(file_id: impl Into<String>) -> Self {
+        ResponseInputContent::InputImage(ResponseInputImage {
+            detail: ImageDetail::Auto,
+            type_field: "input_image".to_string(),
+            file_id: Some(file_id.into()),
+            image_url: None,
+        })
+    }
+
+    /// A file input from an uploaded file.
+    pub fn file_id(file_id: impl Into<String>) -> Self {
+        ResponseInputContent::InputFile(ResponseInputFile { file_id: Some(file_id.into()), ..input_file() })
+    }
+
+    /// A file input from a URL.
+    pub fn file_url(url: impl Into<String>) -> Self {
+        ResponseInputContent::InputFile(ResponseInputFile { file_url: Some(url.into()), ..input_file() })
+    }
+
+    /// A file input sent inline; `data` is a base64 data URL, e.g. `data:application/pdf;base64,...`.
+    pub fn file_data(filename: impl Into<String>, data: impl Into<String>) -> Self {
+        ResponseInputContent::InputFile(ResponseInputFile {
+            file_data: Some(data.into()),
+            filename: Some(filename.into()),
+            ..input_file()
+        })
+    }
+
+    /// Sets the detail level of an image input. Other content is returned unchanged.
+    pub fn with_detail(mut self, detail: ImageDetail) -> Self {
+        if let ResponseInputContent::InputImage(image) = &mut self {
+            image.detail = detail;
+        }
+        self
+    }
+}
+
+fn input_file() -> ResponseInputFile {
+    ResponseInputFile {
+        type_field: "input_file".to_string(),
+        file_data: None,
+        file_id: None,
+        file_url: None,
+        filename: None,
+    }
+}
+
+impl EasyInputMessage {
+    /// A message with the given role.
+    pub fn new(role: MessageRole, content: impl Into<EasyInputMessageContent>) -> Self {
+        EasyInputMessage { content: content.into(), role, type_field: None }
+    }
+
+    /// A user message.
+    pub fn user(content: impl Into<EasyInputMessageContent>) -> Self {
+        Self::new(MessageRole::User, content)
+    }
+
+    /// An assistant message, e.g. a prior turn of the conversation.
+    pub fn assistant(content: impl Into<EasyInputMessageContent>) -> Self {
+        Self::new(MessageRole::Assistant, content)
+    }
+
+    /// A system message.
+    pub fn system(content: impl Into<EasyInputMessageContent>) -> Self {
+        Self::new(MessageRole::System, content)
+    }
+
+    /// A developer message.
+    pub fn developer(content: impl Into<EasyInputMessageContent>) -> Self {
+        Self::new(MessageRole::Developer, content)
+    }
+}
+
+impl From<&str> for EasyInputMessageContent {
+    fn from(text: &str) -> Self {
+        EasyInputMessageContent::String(text.to_string())
+    }
+}
+
+impl From<String> for EasyInputMessageContent {
+    fn from(text: String) -> Self {
+        EasyInputMessageContent::String(text)
+    }
+}
+
+impl From<ResponseInputContent> for EasyInputMessageContent {
+    fn from(content: ResponseInputContent) -> Self {
+        EasyInputMessageContent::List(vec![content])
+    }
+}
+
+impl From<Vec<ResponseInputContent>> for EasyInputMessageContent {
+    fn from(content: Vec<ResponseInputContent>) -> Self {
+        EasyInputMessageContent::List(content)
+    }
+}
+
+impl From<EasyInputMessage> for ResponseInputItem {
+    fn from(message: EasyInputMessage) -> Self {
+        ResponseInputItem::EasyMessage(message)
+    }
+}
+
+//=======================================================================================
+// Builder
+//=======================================================================================
+
+/// Builder state: no input has been added yet.
+#[derive(Debug, Clone, Copy)]
+pub struct NoInput;
+
+/// Builder state: at least one input item has been added.
+#[derive(Debug, Clone, Copy)]
+pub struct HasInput;
+
+/// A builder for [`ResponseCreateParams`], created with [`ResponseCreateParams::builder`].
+#[derive(Debug, Clone)]
+pub struct ResponseCreateParamsBuilder<S = NoInput> {
+    params: ResponseCreateParams,
+    input: Vec<ResponseInputItem>,
+    state: PhantomData<S>,
+}
+
+impl ResponseCreateParams {
+    /// Starts building request parameters for `model`.
+    pub fn builder(model: impl Into<String>) -> ResponseCreateParamsBuilder<NoInput> {
+        ResponseCreateParamsBuilder {
+            params: ResponseCreateParams { model: Some(model.into()), ..Default::default() },
+            input: Vec::new(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<S> ResponseCreateParamsBuilder<S> {
+    /// Appends an input item.
+    pub fn item(mut self, item: impl Into<ResponseInputItem>) -> ResponseCreateParamsBuilder<HasInput> {
+        self.input.push(item.into());
+        ResponseCreateParamsBuilder { params: self.params, input: self.input, state: PhantomData }
+    }
+
+    /// Appends input items.
+    pub fn items<I>(mut self, items: I) -> ResponseCreateParamsBuilder<HasInput>
+    where
+        I: IntoIterator,
+        I::Item: Into<ResponseInputItem>,
+    {
+        self.input.extend(items.into_iter().map(Into::into));
+        ResponseCreateParamsBuilder { params: self.params, input: self.input, state: PhantomData }
+    }
+
+    /// Appends a message with the given role.
+    pub fn message(
+        self,
+        role: MessageRole,
+        content: impl Into<EasyInputMessageContent>,
+    ) -> ResponseCreateParamsBuilder<HasInput> {
+        self.item(EasyInputMessage::new(role, content))
+    }
+
+    /// Appends a user message with the given content.
+    pub fn user(self, content: impl Into<EasyInputMessageContent>) -> ResponseCreateParamsBuilder<HasInput> {
+        self.message(MessageRole::User, content)
+    }
+
+    /// Appends a user text message.
+    pub fn user_text(self, text: impl Into<String>) -> ResponseCreateParamsBuilder<HasInput> {
+        self.user(text.into())
+    }
+
+    /// Appends a user message holding a single image, by URL or base64 data URL.
+    pub fn user_image_url(self, url: impl Into<String>) -> ResponseCreateParamsBuilder<HasInput> {
+        self.user(ResponseInputContent::image_url(url))
+    }
+
+    /// Appends an assistant text message, e.g. a prior turn of the conversation.
+    pub fn assistant_text(self, text: impl Into<String>) -> ResponseCreateParamsBuilder<HasInput> {
+        self.message(MessageRole::Assistant, text.into())
+    }
+
+    /// Appends a developer text message.
+    pub fn developer_text(self, text: impl Into<String>) -> ResponseCreateParamsBuilder<HasInput> {
+        self.message(MessageRole::Developer, text.into())
+    }
+
+    /// Sets the system (or developer) instructions.
+    pub fn instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.params.instructions = Some(ResponseInstructions::String(instructions.into()));
+        self
+    }
+
+    /// Adds a tool the model may call.
+    pub fn tool(mut self, tool: Tool) -> Self {
+        self.params.tools.get_or_insert_with(Vec::new).push(tool);
+        self
+    }
+
+    /// Adds tools the model may call.
+    pub fn tools(mut self, tools: impl IntoIterator<Item = Tool>) -> Self {
+        self.params.tools.get_or_insert_with(Vec::new).extend(tools);
+        self
+    }
+
+    /// Sets how the model should select which tool to use.
+    pub fn tool_choice(mut self, tool_choice: ToolChoice) -> Self {
+        self.params.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// Sets whether the model may run tool calls in parallel.
+    pub fn parallel_tool_calls(mut self, parallel: bool) -> Self {
+        self.params.parallel_tool_calls = Some(parallel);
+        self
+    }
+
+    /// Sets the maximum number of built-in tool calls.
+    pub fn max_tool_calls(mut self, max: i64) -> Self {
+        self.params.max_tool_calls = Some(max);
+        self
+    }
+
+    /// Adds additional output data to include in the response.
+    pub fn include(mut self, includable: ResponseIncludable) -> Self {
+        let include = self.params.include.get_or_insert_with(Vec::new);
+        if !include.contains(&includable) {
+            include.push(includable);
+        }
+        self
+    }
+
+    /// Sets the sampling temperature.
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.params.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the nucleus sampling probability mass.
+    pub fn top_p(mut self, top_p: f64) -> Self {
+        self.params.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets the number of most likely tokens to return at each position.
+    pub fn top_logprobs(mut self, top_logprobs: i64) -> Self {
+        self.params.top_logprobs = Some(top_logprobs);
+        self
+    }
+
+    /// Sets the upper bound on generated tokens, including reasoning tokens.
+    pub fn max_output_tokens(mut self, max: i64) -> Self {
+        self.params.max_output_tokens = Some(max);
+        self
+    }
+
+    /// Sets the reasoning configuration.
+    pub fn reasoning(mut self, reasoning: Reasoning) -> Self {
+        self.params.reasoning = Some(reasoning);
+        self
+    }
+
+    /// Sets the text output configuration.
+    pub fn text(mut self, text: ResponseTextConfig) -> Self {
+        self.params.text = Some(text);
+        self
+    }
+
+    /// Sets the truncation strategy.
+    pub fn truncation(mut self, truncation: TruncationStrategy) -> Self {
+        self.params.truncation = Some(truncation);
+        self
+    }
+
+    /// Sets the previous response to continue from.
+    pub fn previous_response_id(mut self, id: impl Into<String>) -> Self {
+        self.params.previous_response_id = Some(id.into());
+        self
+    }
+
+    /// Sets the conversation this response belongs to.
+    pub fn conversation(mut self, id: impl Into<String>) -> Self {
+        self.params.conversation = Some(ResponseConversationParam::Id(id.into()));
+        self
+    }
+
+    /// Sets whether to store the response for later retrieval.
+    pub fn store(mut self, store: bool) -> Self {
+        self.params.store = Some(store);
+        self
+    }
+
+    /// Sets whether to run the response in the background.
+    pub fn background(mut self, background: bool) -> Self {
+        self.params.background = Some(background);
+        self
+    }
+
+    /// Sets the processing tier.
+    pub fn service_tier(mut self, service_tier: ServiceTier) -> Self {
+        self.params.service_tier = Some(service_tier);
+        self
+    }
+
+    /// Adds a metadata key-value pair.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.metadata.get_or_insert_with(Metadata::new).insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the key used to bucket similar requests for prompt caching.
+    pub fn prompt_cache_key(mut self, key: impl Into<String>) -> Self {
+        self.params.prompt_cache_key = Some(key.into());
+        self
+    }
+
+    /// Sets a stable, hashed identifier for the end user.
+    pub fn safety_identifier(mut self, id: impl Into<String>) -> Self {
+        self.params.safety_identifier = Some(id.into());
+        self
+    }
+
+    /// Sets a body field this crate does not model yet.
+    pub fn extra(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.params.extra.insert(key.into(), value);
+        self
+    }
+}
+
+impl ResponseCreateParamsBuilder<HasInput> {
+    /// Builds the request parameters.
+    pub fn build(self) -> ResponseCreateParams {
+        ResponseCreateParams { input: Some(ResponseInputParam::Items(self.input)), ..self.params }
+    }
+}
@@ -0,0 +1,162 @@
+// src/models/embeddings.rs
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Input text to embed, encoded as a string, an array of strings, or an array of token
+/// arrays.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum EmbeddingInput {
+    String(String),
+    StringArray(Vec<String>),
+    Tokens(Vec<u32>),
+    TokenArrays(Vec<Vec<u32>>),
+}
+
+impl EmbeddingInput {
+    /// The number of separate inputs, each of which gets its own embedding.
+    pub fn len(&self) -> usize {
+        match self {
+            EmbeddingInput::String(_) | EmbeddingInput::Tokens(_) => 1,
+            EmbeddingInput::StringArray(inputs) => inputs.len(),
+            EmbeddingInput::TokenArrays(inputs) => inputs.len(),
+        }
+    }
+
+    /// Whether there is nothing to embed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits the input into consecutive chunks of at most `size` inputs each. Always
+    /// returns at least one chunk.
+    pub fn into_chunks(self, size: usize) -> Vec<EmbeddingInput> {
+        match self {
+            EmbeddingInput::StringArray(inputs) if inputs.len() > size => inputs
+                .chunks(size)
+                .map(|chunk| EmbeddingInput::StringArray(chunk.to_vec()))
+                .collect(),
+            EmbeddingInput::TokenArrays(inputs) if inputs.len() > size => inputs
+                .chunks(size)
+                .map(|chunk| EmbeddingInput::TokenArrays(chunk.to_vec()))
+                .collect(),
+            input => vec![input],
+        }
+    }
+}
+
+impl From<&str> for EmbeddingInput {
+    fn from(input: &str) -> Self {
+        EmbeddingInput::String(input.to_string())
+    }
+}
+
+impl From<String> for EmbeddingInput {
+    fn from(input: String) -> Self {
+        EmbeddingInput::String(input)
+    }
+}
+
+impl From<Vec<String>> for EmbeddingInput {
+    fn from(inputs: Vec<String>) -> Self {
+        EmbeddingInput::StringArray(inputs)
+    }
+}
+
+/// The format to return the embeddings in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EncodingFormat {
+    Float,
+    /// Little-endian `f32`s, base64 encoded. Smaller on the wire; decoded transparently
+    /// into [`Embedding::embedding`].
+    Base64,
+}
+
+/// Parameters for creating embeddings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingCreateParams {
+    /// The input to embed.
+    pub input: EmbeddingInput,
+    /// The ID of the embedding model to use, e.g. `text-embedding-3-small`.
+    pub model: String,
+    /// The number of dimensions the resulting embeddings should have. Only supported by
+    /// `text-embedding-3` and later models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+    /// The format to return the embeddings in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding_format: Option<EncodingFormat>,
+    /// A unique identifier representing your end-user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl EmbeddingCreateParams {
+    /// Creates parameters to embed `input` with `model`.
+    pub fn new(model: impl Into<String>, input: impl Into<EmbeddingInput>) -> Self {
+        Self { input: input.into(), model: model.into(), dimensions: None, encoding_format: None, user: None }
+    }
+}
+
+/// An embedding vector returned by the embedding endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Embedding {
+    /// The embedding vector. Base64-encoded embeddings are decoded on deserialization.
+    #[serde(deserialize_with = "deserialize_embedding")]
+    pub embedding: Vec<f32>,
+    /// The index of the embedding in the list of inputs.
+    pub index: usize,
+    /// The object type, which is always `embedding`.
+    pub object: String,
+}
+
+/// The token usage of an embedding request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct EmbeddingUsage {
+    /// The number of tokens used by the input.
+    pub prompt_tokens: i64,
+    /// The total number of tokens used by the request.
+    pub total_tokens: i64,
+}
+
+/// The result of an embedding request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CreateEmbeddingResponse {
+    /// The embeddings, one per input, in input order.
+    pub data: Vec<Embedding>,
+    /// The name of the model used to generate the embeddings.
+    pub model: String,
+    /// The object type, which is always `list`.
+    pub object: String,
+    /// The token usage of the request.
+    pub usage: EmbeddingUsage,
+}
+
+fn deserialize_embedding<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f32>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Encoded {
+        Float(Vec<f32>),
+        Base64(String),
+    }
+
+    match Encoded::deserialize(deserializer)? {
+        Encoded::Float(values) => Ok(values),
+        Encoded::Base64(encoded) => {
+            let bytes = STANDARD.decode(encoded).map_err(serde::de::Error::custom)?;
+            if bytes.len() % 4 != 0 {
+                return Err(serde::de::Error::custom(format!(
+                    "base64 embedding has {} bytes, which is not a whole number of f32s",
+                    bytes.len()
+                )));
+            }
+            Ok(bytes
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect())
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// src/models/files.rs
+
+use crate::models::responses::ListOrder;
+use serde::{Deserialize, Serialize};
+
+/// The intended purpose of an uploaded file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum FilePurpose {
+    Assistants,
+    AssistantsOutput,
+    Batch,
+    BatchOutput,
+    #[serde(rename = "fine-tune")]
+    FineTune,
+    #[serde(rename = "fine-tune-results")]
+    FineTuneResults,
+    Vision,
+    UserData,
+    Evals,
+}
+
+impl FilePurpose {
+    /// The purpose as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilePurpose::Assistants => "assistants",
+            FilePurpose::AssistantsOutput => "assistants_output",
+            FilePurpose::Batch => "batch",
+            FilePurpose::BatchOutput => "batch_output",
+            FilePurpose::FineTune => "fine-tune",
+            FilePurpose::FineTuneResults => "fine-tune-results",
+            FilePurpose::Vision => "vision",
+            FilePurpose::UserData => "user_data",
+            FilePurpose::Evals => "evals",
+        }
+    }
+}
+
+/// The processing status of an uploaded file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStatus {
+    Uploaded,
+    Processed,
+    Error,
+}
+
+/// A document that has been uploaded to the API.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileObject {
+    /// The file identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The size of the file, in bytes.
+    pub bytes: u64,
+    /// The Unix timestamp (in seconds) for when the file was created.
+    pub created_at: i64,
+    /// The name of the file.
+    pub filename: String,
+    /// The object type, which is always `file`.
+    pub object: String,
+    /// The intended purpose of the file.
+    pub purpose: FilePurpose,
+    /// The Unix timestamp (in seconds) for when the file will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+    /// Deprecated. The current status of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FileStatus>,
+    /// Deprecated. Details on why a fine-tuning training file failed validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<String>,
+}
+
+/// Parameters for uploading a file from memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileCreateParams {
+    /// The contents of the file.
+    pub file: Vec<u8>,
+    /// The name the file is uploaded under.
+    pub filename: String,
+    /// The intended purpose of the uploaded file.
+    pub purpose: FilePurpose,
+}
+
+/// Parameters for listing files.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FileListParams {
+    /// A cursor for use in pagination; the ID of the last file of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A limit on the number of objects to be returned, between 1 and 10,000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<ListOrder>,
+    /// Only return files with the given purpose.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<FilePurpose>,
+}
+
+/// The result of deleting a file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileDeleted {
+    pub id: String,
+    /// Whether the file was deleted.
+    pub deleted: bool,
+    /// The object type, which is always `file`.
+    pub object: String,
+}
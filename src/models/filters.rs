@@ -0,0 +1,245 @@
+// src/models/filters.rs
+
+//! Attribute filters for file search and vector store search, with a small builder DSL:
+//!
+//! ```
+//! use sh_openai_responses::models::filters::attr;
+//!
+//! let filter = attr("dept").eq("cardiology").and(attr("year").gte(2023));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+
+//=======================================================================================
+// Models
+//=======================================================================================
+
+/// The operator of a comparison filter.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    /// Equals.
+    Eq,
+    /// Not equal.
+    Ne,
+    /// Greater than.
+    Gt,
+    /// Greater than or equal.
+    Gte,
+    /// Less than.
+    Lt,
+    /// Less than or equal.
+    Lte,
+    /// The attribute is one of the given values.
+    In,
+    /// The attribute is none of the given values.
+    Nin,
+}
+
+/// The value an attribute is compared against.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum FilterValue {
+    Boolean(bool),
+    Number(Number),
+    String(String),
+    /// A list of values, used with the `in` and `nin` operators.
+    Array(Vec<FilterValue>),
+}
+
+/// Compares an attribute key against a value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ComparisonFilter {
+    /// The attribute key to compare.
+    pub key: String,
+    /// The comparison operator.
+    #[serde(rename = "type")]
+    pub type_field: ComparisonOperator,
+    /// The value to compare the attribute against.
+    pub value: FilterValue,
+}
+
+/// The operator of a compound filter.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompoundOperator {
+    And,
+    Or,
+}
+
+/// Combines multiple filters using `and` or `or`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompoundFilter {
+    /// The filters to combine.
+    pub filters: Vec<Filter>,
+    /// The logical operator.
+    #[serde(rename = "type")]
+    pub type_field: CompoundOperator,
+}
+
+/// A filter to apply.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum Filter {
+    Comparison(ComparisonFilter),
+    Compound(CompoundFilter),
+}
+
+//=======================================================================================
+// Builder DSL
+//=======================================================================================
+
+/// Starts a comparison on the attribute `key`.
+pub fn attr(key: impl Into<String>) -> Attribute {
+    Attribute { key: key.into() }
+}
+
+/// An attribute key awaiting a comparison. Created with [`attr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    key: String,
+}
+
+impl Attribute {
+    /// The attribute equals `value`.
+    pub fn eq(self, value: impl Into<FilterValue>) -> Filter {
+        self.compare(ComparisonOperator::Eq, value.into())
+    }
+
+    /// The attribute does not equal `value`.
+    pub fn ne(self, value: impl Into<FilterValue>) -> Filter {
+        self.compare(ComparisonOperator::Ne, value.into())
+    }
+
+    /// The attribute is greater than `value`.
+    pub fn gt(self, value: impl Into<FilterValue>) -> Filter {
+        self.compare(ComparisonOperator::Gt, value.into())
+    }
+
+    /// The attribute is greater than or equal to `value`.
+    pub fn gte(self, value: impl Into<FilterValue>) -> Filter {
+        self.compare(ComparisonOperator::Gte, value.into())
+    }
+
+    /// The attribute is less than `value`.
+    pub fn lt(self, value: impl Into<FilterValue>) -> Filter {
+        self.compare(ComparisonOperator::Lt, value.into())
+    }
+
+    /// The attribute is less than or equal to `value`.
+    pub fn lte(self, value: impl Into<FilterValue>) -> Filter {
+        self.compare(ComparisonOperator::Lte, value.into())
+    }
+
+    /// The attribute is one of `values`.
+    pub fn is_in<V: Into<FilterValue>>(self, values: impl IntoIterator<Item = V>) -> Filter {
+        self.compare(ComparisonOperator::In, FilterValue::from_iter(values))
+    }
+
+    /// The attribute is none of `values`.
+    pub fn not_in<V: Into<FilterValue>>(self, values: impl IntoIterator<Item = V>) -> Filter {
+        self.compare(ComparisonOperator::Nin, FilterValue::from_iter(values))
+    }
+
+    fn compare(self, operator: ComparisonOperator, value: FilterValue) -> Filter {
+        Filter::Comparison(ComparisonFilter { key: self.key, type_field: operator, value })
+    }
+}
+
+impl Filter {
+    /// Matches when every filter matches.
+    pub fn all(filters: impl IntoIterator<Item = Filter>) -> Filter {
+        Filter::Compound(CompoundFilter {
+            filters: filters.into_iter().collect(),
+            type_field: CompoundOperator::And,
+        })
+    }
+
+    /// Matches when any filter matches.
+    pub fn any(filters: impl IntoIterator<Item = Filter>) -> Filter {
+        Filter::Compound(CompoundFilter {
+            filters: filters.into_iter().collect(),
+            type_field: CompoundOperator::Or,
+        })
+    }
+
+    /// Matches when both this filter and `other` match. Chained calls produce a single flat
+    /// `and` filter.
+    pub fn and(self, other: Filter) -> Filter {
+        self.combine(CompoundOperator::And, other)
+    }
+
+    /// Matches when either this filter or `other` matches. Chained calls produce a single
+    /// flat `or` filter.
+    pub fn or(self, other: Filter) -> Filter {
+        self.combine(CompoundOperator::Or, other)
+    }
+
+    fn combine(self, operator: CompoundOperator, other: Filter) -> Filter {
+        match self {
+            Filter::Compound(mut compound) if compound.type_field == operator => {
+                compound.filters.push(other);
+                Filter::Compound(compound)
+            }
+            filter => Filter::Compound(CompoundFilter { filters: vec![filter, other], type_field: operator }),
+        }
+    }
+}
+
+impl From<ComparisonFilter> for Filter {
+    fn from(filter: ComparisonFilter) -> Self {
+        Filter::Comparison(filter)
+    }
+}
+
+impl From<CompoundFilter> for Filter {
+    fn from(filter: CompoundFilter) -> Self {
+        Filter::Compound(filter)
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        FilterValue::Boolean(value)
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::String(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::String(value)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for FilterValue {
+            fn from(value: $ty) -> Self {
+                FilterValue::Number(value.into())
+            }
+        }
+    )*};
+}
+
+impl_from_integer!(i32, i64, u32, u64);
+
+/// # Panics
+///
+/// Panics if `value` is NaN or infinite, which cannot be represented in JSON.
+impl From<f64> for FilterValue {
+    fn from(value: f64) -> Self {
+        FilterValue::Number(Number::from_f64(value).expect("filter values must be finite numbers"))
+    }
+}
+
+impl<V: Into<FilterValue>> FromIterator<V> for FilterValue {
+    fn from_iter<I: IntoIterator<Item = V>>(values: I) -> Self {
+        FilterValue::Array(values.into_iter().map(Into::into).collect())
+    }
+}
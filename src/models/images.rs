@@ -0,0 +1,254 @@
+// src/models/images.rs
+
+use crate::models::responses::{
+    ResponseImageGenCallPartialImageEvent, Response, ResponseOutputItem, ToolImageGenerationInputImageMask,
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+
+/// The format generated images are returned in. `gpt-image-1` always returns `b64_json`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageResponseFormat {
+    Url,
+    B64Json,
+}
+
+impl ImageResponseFormat {
+    /// The format as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageResponseFormat::Url => "url",
+            ImageResponseFormat::B64Json => "b64_json",
+        }
+    }
+}
+
+/// Parameters for generating images from a prompt.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImageGenerateParams {
+    /// A text description of the desired image(s).
+    pub prompt: String,
+    /// The model to use, e.g. `gpt-image-1` or `dall-e-3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The number of images to generate, between 1 and 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    /// The size of the generated images, e.g. `1024x1024` or `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// The quality of the image, e.g. `low`, `medium`, `high`, `hd` or `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    /// Background transparency: `transparent`, `opaque` or `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    /// Content moderation level: `low` or `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<String>,
+    /// The output format: `png`, `jpeg` or `webp`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<String>,
+    /// The compression level (0-100%) for `jpeg` and `webp` output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_compression: Option<u8>,
+    /// The format images are returned in. Only supported by `dall-e` models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ImageResponseFormat>,
+    /// The style of the generated images: `vivid` or `natural`. Only supported by `dall-e-3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// A unique identifier representing your end-user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+/// An image uploaded as part of a multipart request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageUpload {
+    /// The image contents.
+    pub data: Vec<u8>,
+    /// The name the image is uploaded under. Its extension tells the API the image format.
+    pub filename: String,
+}
+
+impl ImageUpload {
+    /// Creates an upload from image bytes.
+    pub fn new(data: Vec<u8>, filename: impl Into<String>) -> Self {
+        Self { data, filename: filename.into() }
+    }
+
+    /// Creates an upload from a base64 data URL such as `data:image/png;base64,...`.
+    pub fn from_data_url(url: &str, filename: impl Into<String>) -> Option<Self> {
+        let (_, encoded) = url.strip_prefix("data:")?.split_once(";base64,")?;
+        let data = STANDARD.decode(encoded).ok()?;
+        Some(Self { data, filename: filename.into() })
+    }
+}
+
+impl TryFrom<&ToolImageGenerationInputImageMask> for ImageUpload {
+    type Error = String;
+
+    /// Converts the mask of an image generation tool into an upload for
+    /// [`ImageEditParams::mask`]. Only data URL masks can be converted; masks referencing
+    /// a file ID must be downloaded first.
+    fn try_from(mask: &ToolImageGenerationInputImageMask) -> Result<Self, Self::Error> {
+        match (&mask.image_url, &mask.file_id) {
+            (Some(url), _) => {
+                ImageUpload::from_data_url(url, "mask.png").ok_or_else(|| "Mask image_url is not a base64 data URL".to_string())
+            }
+            (None, Some(file_id)) => Err(format!("Mask references file '{file_id}'; download it to upload it")),
+            (None, None) => Err("Mask has neither an image_url nor a file_id".to_string()),
+        }
+    }
+}
+
+/// Parameters for editing or extending images. Sent as a multipart form.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImageEditParams {
+    /// The image(s) to edit. `gpt-image-1` accepts up to 16 images; `dall-e-2` accepts one
+    /// square png.
+    pub images: Vec<ImageUpload>,
+    /// A text description of the desired image(s).
+    pub prompt: String,
+    /// A png whose fully transparent areas indicate where the first image should be edited.
+    pub mask: Option<ImageUpload>,
+    /// The model to use, e.g. `gpt-image-1` or `dall-e-2`.
+    pub model: Option<String>,
+    /// The number of images to generate, between 1 and 10.
+    pub n: Option<u32>,
+    /// The size of the generated images, e.g. `1024x1024` or `auto`.
+    pub size: Option<String>,
+    /// The quality of the image, e.g. `low`, `medium`, `high` or `auto`.
+    pub quality: Option<String>,
+    /// Background transparency: `transparent`, `opaque` or `auto`.
+    pub background: Option<String>,
+    /// The output format: `png`, `jpeg` or `webp`.
+    pub output_format: Option<String>,
+    /// The compression level (0-100%) for `jpeg` and `webp` output.
+    pub output_compression: Option<u8>,
+    /// How closely to match the style and features of the input images: `high` or `low`.
+    pub input_fidelity: Option<String>,
+    /// The format images are returned in. Only supported by `dall-e-2`.
+    pub response_format: Option<ImageResponseFormat>,
+    /// A unique identifier representing your end-user.
+    pub user: Option<String>,
+}
+
+/// Parameters for creating variations of an image. Sent as a multipart form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageVariationParams {
+    /// A square png of less than 4MB to use as the basis for the variations.
+    pub image: ImageUpload,
+    /// The model to use. Only `dall-e-2` is supported.
+    pub model: Option<String>,
+    /// The number of images to generate, between 1 and 10.
+    pub n: Option<u32>,
+    /// The size of the generated images: `256x256`, `512x512` or `1024x1024`.
+    pub size: Option<String>,
+    /// The format images are returned in.
+    pub response_format: Option<ImageResponseFormat>,
+    /// A unique identifier representing your end-user.
+    pub user: Option<String>,
+}
+
+impl ImageVariationParams {
+    /// Creates parameters for variations of `image`.
+    pub fn new(image: ImageUpload) -> Self {
+        Self { image, model: None, n: None, size: None, response_format: None, user: None }
+    }
+}
+
+/// A generated image.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Image {
+    /// The base64-encoded image, for `b64_json` responses and `gpt-image-1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b64_json: Option<String>,
+    /// The URL of the image, for `url` responses. Valid for 60 minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The prompt used to generate the image, if it was revised (`dall-e-3` only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revised_prompt: Option<String>,
+}
+
+/// The token usage of a `gpt-image-1` request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImageUsage {
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub total_tokens: i64,
+}
+
+/// The result of an image generation, edit or variation request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImagesResponse {
+    /// The Unix timestamp (in seconds) of when the images were created.
+    pub created: i64,
+    /// The generated images.
+    #[serde(default)]
+    pub data: Vec<Image>,
+    /// The background of the generated images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    /// The output format of the generated images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<String>,
+    /// The quality of the generated images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    /// The size of the generated images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// The token usage of the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ImageUsage>,
+}
+
+//=======================================================================================
+// Generated Image Sources
+//=======================================================================================
+
+/// Anything that carries base64-encoded generated images.
+pub trait GeneratedImages {
+    /// The base64-encoded images, in order.
+    fn base64_images(&self) -> Vec<&str>;
+}
+
+impl GeneratedImages for ImagesResponse {
+    fn base64_images(&self) -> Vec<&str> {
+        self.data.iter().filter_map(|image| image.b64_json.as_deref()).collect()
+    }
+}
+
+impl GeneratedImages for Response {
+    /// The results of the response's image generation calls.
+    fn base64_images(&self) -> Vec<&str> {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                ResponseOutputItem::ImageGenerationCall(call) => call.result.as_deref(),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl GeneratedImages for ResponseImageGenCallPartialImageEvent {
+    fn base64_images(&self) -> Vec<&str> {
+        vec![self.partial_image_b64.as_str()]
+    }
+}
+
+/// Guesses the file extension of image bytes from their signature, falling back to `png`.
+pub fn image_extension(data: &[u8]) -> &'static str {
+    match data {
+        [0xFF, 0xD8, 0xFF, ..] => "jpeg",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "webp",
+        [b'G', b'I', b'F', b'8', ..] => "gif",
+        _ => "png",
+    }
+}
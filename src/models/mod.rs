@@ -1 +1,12 @@
-pub mod responses;
\ No newline at end of file
+pub mod responses;
+pub mod files;
+pub mod batches;
+pub mod vector_stores;
+pub mod filters;
+pub mod embeddings;
+pub mod moderations;
+pub mod audio;
+pub mod images;
+pub mod model;
+pub mod builder;
+pub mod outcome;
\ No newline at end of file
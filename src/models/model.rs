@@ -0,0 +1,26 @@
+// src/models/model.rs
+
+use serde::{Deserialize, Serialize};
+
+/// A model that can be used with the API.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Model {
+    /// The model identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) when the model was created.
+    pub created: i64,
+    /// The object type, which is always `model`.
+    pub object: String,
+    /// The organization that owns the model.
+    pub owned_by: String,
+}
+
+/// The result of deleting a fine-tuned model.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModelDeleted {
+    pub id: String,
+    /// Whether the model was deleted.
+    pub deleted: bool,
+    /// The object type, which is always `model`.
+    pub object: String,
+}
@@ -0,0 +1,201 @@
+// src/models/moderations.rs
+
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Input to classify: a string, an array of strings, or an array of multi-modal inputs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ModerationInput {
+    String(String),
+    StringArray(Vec<String>),
+    MultiModal(Vec<ModerationMultiModalInput>),
+}
+
+impl From<&str> for ModerationInput {
+    fn from(input: &str) -> Self {
+        ModerationInput::String(input.to_string())
+    }
+}
+
+impl From<String> for ModerationInput {
+    fn from(input: String) -> Self {
+        ModerationInput::String(input)
+    }
+}
+
+/// A text or image input to classify.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModerationMultiModalInput {
+    Text { text: String },
+    ImageUrl { image_url: ModerationImageUrl },
+}
+
+/// An image to classify.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModerationImageUrl {
+    /// A URL of the image, or the base64 encoded image data as a data URL.
+    pub url: String,
+}
+
+/// Parameters for classifying text and images.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationCreateParams {
+    /// The input to classify.
+    pub input: ModerationInput,
+    /// The moderation model to use, e.g. `omni-moderation-latest`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl ModerationCreateParams {
+    /// Creates parameters to classify `input` with the default model.
+    pub fn new(input: impl Into<ModerationInput>) -> Self {
+        Self { input: input.into(), model: None }
+    }
+}
+
+/// The kind of input a category was flagged for.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationInputType {
+    Text,
+    Image,
+}
+
+/// Whether each category was flagged.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ModerationCategories {
+    pub harassment: bool,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: bool,
+    pub hate: bool,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: bool,
+    /// Not returned by legacy text moderation models.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub illicit: bool,
+    /// Not returned by legacy text moderation models.
+    #[serde(rename = "illicit/violent", default, deserialize_with = "null_as_default")]
+    pub illicit_violent: bool,
+    #[serde(rename = "self-harm")]
+    pub self_harm: bool,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: bool,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: bool,
+    pub sexual: bool,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: bool,
+    pub violence: bool,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: bool,
+}
+
+impl ModerationCategories {
+    /// The API names of the flagged categories.
+    pub fn flagged(&self) -> Vec<&'static str> {
+        [
+            ("harassment", self.harassment),
+            ("harassment/threatening", self.harassment_threatening),
+            ("hate", self.hate),
+            ("hate/threatening", self.hate_threatening),
+            ("illicit", self.illicit),
+            ("illicit/violent", self.illicit_violent),
+            ("self-harm", self.self_harm),
+            ("self-harm/instructions", self.self_harm_instructions),
+            ("self-harm/intent", self.self_harm_intent),
+            ("sexual", self.sexual),
+            ("sexual/minors", self.sexual_minors),
+            ("violence", self.violence),
+            ("violence/graphic", self.violence_graphic),
+        ]
+        .into_iter()
+        .filter_map(|(name, flagged)| flagged.then_some(name))
+        .collect()
+    }
+}
+
+/// The score of each category, between 0 and 1.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ModerationCategoryScores {
+    pub harassment: f64,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: f64,
+    pub hate: f64,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub illicit: f64,
+    #[serde(rename = "illicit/violent", default, deserialize_with = "null_as_default")]
+    pub illicit_violent: f64,
+    #[serde(rename = "self-harm")]
+    pub self_harm: f64,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: f64,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: f64,
+    pub sexual: f64,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: f64,
+    pub violence: f64,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: f64,
+}
+
+impl ModerationCategoryScores {
+    /// The highest score across all categories.
+    pub fn max(&self) -> f64 {
+        [
+            self.harassment,
+            self.harassment_threatening,
+            self.hate,
+            self.hate_threatening,
+            self.illicit,
+            self.illicit_violent,
+            self.self_harm,
+            self.self_harm_instructions,
+            self.self_harm_intent,
+            self.sexual,
+            self.sexual_minors,
+            self.violence,
+            self.violence_graphic,
+        ]
+        .into_iter()
+        .fold(0.0, f64::max)
+    }
+}
+
+/// The classification of a single input.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModerationResult {
+    /// Whether any of the categories was flagged.
+    pub flagged: bool,
+    /// Whether each category was flagged.
+    pub categories: ModerationCategories,
+    /// The score of each category.
+    pub category_scores: ModerationCategoryScores,
+    /// The input types each category was flagged for. Only returned by omni models.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category_applied_input_types: Option<HashMap<String, Vec<ModerationInputType>>>,
+}
+
+/// The result of a moderation request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModerationCreateResponse {
+    /// The unique identifier for the moderation request.
+    pub id: String,
+    /// The model used to generate the moderation results.
+    pub model: String,
+    /// A list of moderation results, one per input.
+    pub results: Vec<ModerationResult>,
+}
+
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
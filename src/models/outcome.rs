@@ -0,0 +1,97 @@
+// src/models/outcome.rs
+
+//! The terminal outcome of a response.
+//!
+//! [`ResponseOutcome`] interprets a response's `status`, `error` and `incomplete_details`
+//! so callers do not each have to (`ItemStatus` also covers states only output items
+//! can be in).
+
+use crate::models::responses::{
+    IncompleteDetailsReason, Response, ResponseError, ResponseErrorCode, ResponseStatus, ResponseStreamEvent,
+};
+use std::fmt;
+
+/// How a response ended, or that it has not yet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseOutcome {
+    /// The model finished generating the response.
+    Completed,
+    /// Generation stopped early, e.g. at `max_output_tokens`.
+    Incomplete { reason: Option<IncompleteDetailsReason> },
+    /// The model failed to generate the response.
+    Failed(ResponseError),
+    /// The response was cancelled.
+    Cancelled,
+    /// The response is still queued or in progress.
+    Pending,
+}
+
+impl ResponseOutcome {
+    /// Whether the response completed.
+    pub fn is_completed(&self) -> bool {
+        matches!(self, ResponseOutcome::Completed)
+    }
+
+    /// Whether the response has stopped, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, ResponseOutcome::Pending)
+    }
+
+    /// The outcome of the final event of a response stream: `response.completed`,
+    /// `response.failed` or `response.incomplete`. Other events give `None`.
+    pub fn from_event(event: &ResponseStreamEvent) -> Option<Self> {
+        match event {
+            ResponseStreamEvent::ResponseCompleted(event) => Some(event.response.outcome()),
+            ResponseStreamEvent::ResponseFailed(event) => Some(event.response.outcome()),
+            ResponseStreamEvent::ResponseIncomplete(event) => Some(event.response.outcome()),
+            _ => None,
+        }
+    }
+}
+
+impl Response {
+    /// The outcome of this response.
+    ///
+    /// Without a `status`, a response with an `error` is failed, one with
+    /// `incomplete_details` is incomplete, and any other is completed.
+    pub fn outcome(&self) -> ResponseOutcome {
+        let incomplete = || ResponseOutcome::Incomplete {
+            reason: self.incomplete_details.as_ref().and_then(|details| details.reason.clone()),
+        };
+        let failed = || {
+            ResponseOutcome::Failed(self.error.clone().unwrap_or_else(|| ResponseError {
+                code: ResponseErrorCode::ServerError,
+                message: "The response failed without an error".to_string(),
+            }))
+        };
+        match &self.status {
+            Some(ResponseStatus::Completed) => ResponseOutcome::Completed,
+            Some(ResponseStatus::Incomplete) => incomplete(),
+            Some(ResponseStatus::Failed) => failed(),
+            Some(ResponseStatus::Cancelled) => ResponseOutcome::Cancelled,
+            Some(_) => ResponseOutcome::Pending,
+            None if self.error.is_some() => failed(),
+            None if self.incomplete_details.is_some() => incomplete(),
+            None => ResponseOutcome::Completed,
+        }
+    }
+}
+
+impl From<&Response> for ResponseOutcome {
+    fn from(response: &Response) -> Self {
+        response.outcome()
+    }
+}
+
+impl fmt::Display for ResponseOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResponseOutcome::Completed => f.write_str("completed"),
+            ResponseOutcome::Incomplete { reason: Some(reason) } => write!(f, "incomplete ({reason:?})"),
+            ResponseOutcome::Incomplete { reason: None } => f.write_str("incomplete"),
+            ResponseOutcome::Failed(error) => write!(f, "failed: [{:?}] {}", error.code, error.message),
+            ResponseOutcome::Cancelled => f.write_str("cancelled"),
+            ResponseOutcome::Pending => f.write_str("pending"),
+        }
+    }
+}
@@ -5,6 +5,43 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
+pub use crate::models::filters::{
+    ComparisonFilter, ComparisonOperator, CompoundFilter, CompoundOperator, Filter, FilterValue,
+};
+
+/// Implements `Deserialize` for an untagged enum by dispatching on the `type` field.
+///
+/// Used where each variant's struct carries its own `type` field, so a serde internally
+/// tagged representation would emit the tag twice. Unknown types are an error unless a
+/// fallback variant holding the raw `Value` is given with `else`.
+macro_rules! deserialize_by_type {
+    ($name:ident { $($tag:literal => $variant:ident),* $(,)? }) => {
+        deserialize_by_type!(@impl $name { $($tag => $variant),* } |other, _value| {
+            return Err(D::Error::custom(format!(concat!("unknown ", stringify!($name), " type `{}`"), other)))
+        });
+    };
+    ($name:ident { $($tag:literal => $variant:ident),* $(,)? } else $fallback:ident) => {
+        deserialize_by_type!(@impl $name { $($tag => $variant),* } |_other, value| {
+            return Ok($name::$fallback(value))
+        });
+    };
+    (@impl $name:ident { $($tag:literal => $variant:ident),* } |$other:ident, $value:ident| $unknown:block) => {
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let $value = Value::deserialize(deserializer)?;
+                let item_type = $value.get("type").and_then(Value::as_str).unwrap_or_default().to_string();
+                match item_type.as_str() {
+                    $($tag => serde_json::from_value($value).map($name::$variant),)*
+                    $other => $unknown,
+                }
+                .map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
 //=======================================================================================
 // Re-usable & Shared Primitives
 //=======================================================================================
@@ -86,7 +123,7 @@ pub enum IncompleteDetailsReason {
 }
 
 /// The latency tier for processing a request.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum ServiceTier {
     Auto,
@@ -204,35 +241,41 @@ pub struct ResponseInputFile {
 }
 
 /// Multi-modal input contents.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ResponseInputContent {
     InputText(ResponseInputText),
     InputImage(ResponseInputImage),
     InputFile(ResponseInputFile),
 }
 
-/// Placeholder for pagination structure.
+deserialize_by_type!(ResponseInputContent {
+    "input_text" => InputText,
+    "input_image" => InputImage,
+    "input_file" => InputFile,
+});
+
+/// A page of a cursor-paginated list.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CursorPage<T> {
     pub data: Vec<T>,
-    // other pagination fields like 'has_more', 'next_cursor', etc. would go here
-}
-
-/// Placeholder for a comparison filter.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ComparisonFilter {}
-
-/// Placeholder for a compound filter.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct CompoundFilter {}
-
-/// A filter to apply.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(untagged)]
-pub enum Filter {
-    Comparison(ComparisonFilter),
-    Compound(CompoundFilter),
+    /// The ID of the first item in the page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+    /// The ID of the last item in the page; pass it as `after` to fetch the next page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+    /// Whether there are more items after this page.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// The sort order of a paginated list, by the `created_at` timestamp of its objects.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListOrder {
+    Asc,
+    Desc,
 }
 
 /// A tool that searches for relevant content from uploaded files.
@@ -255,14 +298,40 @@ pub struct FileSearchTool {
 }
 
 /// Ranking options for search.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct FileSearchToolRankingOptions {
     /// The ranker to use for the file search.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ranker: Option<String>,
-    /// The score threshold for the file search.
+    pub ranker: Option<FileSearchRanker>,
+    /// The score threshold for the file search, between 0 and 1. Results scoring below
+    /// the threshold are dropped.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub score_threshold: Option<f64>,
+    /// Weights used to combine semantic and keyword matches in hybrid search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hybrid_search: Option<HybridSearchOptions>,
+}
+
+/// The ranker used to order file search results.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FileSearchRanker {
+    /// Let the API pick the ranker.
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "default-2024-11-15")]
+    Default20241115,
+    /// A ranker not known to this crate.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Weights for reciprocal rank fusion in hybrid file search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HybridSearchOptions {
+    /// The weight of the embedding (semantic) matches.
+    pub embedding_weight: f64,
+    /// The weight of the text (keyword) matches.
+    pub text_weight: f64,
 }
 
 /// Defines a function in your own code the model can choose to call.
@@ -560,6 +629,11 @@ pub struct ResponseUsage {
     pub output_tokens_details: ResponseUsageOutputTokensDetails,
     /// The total number of tokens used.
     pub total_tokens: i64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// A detailed breakdown of the input tokens.
@@ -662,6 +736,11 @@ pub struct ResponseOutputText {
     pub type_field: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logprobs: Option<Vec<ResponseOutputTextLogprob>>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// A refusal from the model.
@@ -672,10 +751,15 @@ pub struct ResponseOutputRefusal {
     /// The type of the refusal. Always `refusal`.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ResponseContent {
     OutputText(ResponseOutputText),
     OutputRefusal(ResponseOutputRefusal),
@@ -684,6 +768,14 @@ pub enum ResponseContent {
     InputFile(ResponseInputFile),
 }
 
+deserialize_by_type!(ResponseContent {
+    "output_text" => OutputText,
+    "refusal" => OutputRefusal,
+    "input_text" => InputText,
+    "input_image" => InputImage,
+    "input_file" => InputFile,
+});
+
 /// An output message from the model.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseOutputMessage {
@@ -698,6 +790,11 @@ pub struct ResponseOutputMessage {
     /// The type of the output message. Always `message`.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -722,6 +819,11 @@ pub struct ResponseFileSearchToolCall {
     /// The results of the file search tool call.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<ResponseFileSearchToolCallResult>>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -761,6 +863,11 @@ pub struct ResponseFunctionToolCall {
     /// The status of the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<ItemStatus>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// The results of a web search tool call.
@@ -773,6 +880,11 @@ pub struct ResponseFunctionWebSearch {
     /// The type of the web search tool call.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// A click action.
@@ -884,8 +996,8 @@ pub struct ResponseComputerToolCallWait {
     pub type_field: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ResponseComputerToolCallAction {
     Click(ResponseComputerToolCallClick),
     DoubleClick(ResponseComputerToolCallDoubleClick),
@@ -898,6 +1010,18 @@ pub enum ResponseComputerToolCallAction {
     Wait(ResponseComputerToolCallWait),
 }
 
+deserialize_by_type!(ResponseComputerToolCallAction {
+    "click" => Click,
+    "double_click" => DoubleClick,
+    "drag" => Drag,
+    "keypress" => Keypress,
+    "move" => Move,
+    "screenshot" => Screenshot,
+    "scroll" => Scroll,
+    "type" => Type,
+    "wait" => Wait,
+});
+
 /// A pending safety check for the computer call.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseComputerToolCallPendingSafetyCheck {
@@ -925,6 +1049,51 @@ pub struct ResponseComputerToolCall {
     /// The type of the computer call.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// How much effort a reasoning model spends reasoning before it responds.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningEffort {
+    Minimal,
+    Low,
+    Medium,
+    High,
+    /// An effort level not known to this crate.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The level of detail of the reasoning summary.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningSummary {
+    Auto,
+    Concise,
+    Detailed,
+    /// A summary level not known to this crate.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Configuration options for reasoning models.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Reasoning {
+    /// Constrains the effort on reasoning. Lower effort gives faster responses and uses
+    /// fewer reasoning tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffort>,
+    /// A summary of the reasoning performed by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ReasoningSummary>,
+    /// Deprecated. Use `summary` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_summary: Option<ReasoningSummary>,
 }
 
 /// A description of the chain of thought used by a reasoning model.
@@ -943,6 +1112,11 @@ pub struct ResponseReasoningItem {
     /// The status of the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<ItemStatus>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -966,6 +1140,11 @@ pub struct ImageGenerationCall {
     /// The type of the image generation call.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// The logs output from the code interpreter.
@@ -988,13 +1167,18 @@ pub struct ResponseCodeInterpreterToolCallImage {
     pub url: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ResponseCodeInterpreterToolCallOutput {
     Logs(ResponseCodeInterpreterToolCallLogs),
     Image(ResponseCodeInterpreterToolCallImage),
 }
 
+deserialize_by_type!(ResponseCodeInterpreterToolCallOutput {
+    "logs" => Logs,
+    "image" => Image,
+});
+
 /// A tool call to run code.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseCodeInterpreterToolCall {
@@ -1011,6 +1195,11 @@ pub struct ResponseCodeInterpreterToolCall {
     /// The type of the code interpreter tool call.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// A tool call to run a command on the local shell.
@@ -1027,6 +1216,11 @@ pub struct LocalShellCall {
     /// The type of the local shell call.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// Execute a shell command on the server.
@@ -1070,6 +1264,11 @@ pub struct McpCall {
     /// The output from the tool call.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output: Option<String>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// A list of tools available on an MCP server.
@@ -1087,6 +1286,11 @@ pub struct McpListTools {
     /// Error message if the server could not list tools.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// A tool available on an MCP server.
@@ -1118,10 +1322,20 @@ pub struct McpApprovalRequest {
     /// The type of the item. Always `mcp_approval_request`.
     #[serde(rename = "type")]
     pub type_field: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// An output item from the model.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Serialized untagged (each item carries its own `type` field) but deserialized by
+/// `type`: several items share the same required fields, so untagged matching would pick
+/// the first variant that fits (e.g. an `image_generation_call` read as a web search call).
+/// Item types this crate does not know are kept as [`ResponseOutputItem::Other`].
+#[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(untagged)]
 pub enum ResponseOutputItem {
     Message(ResponseOutputMessage),
@@ -1136,7 +1350,24 @@ pub enum ResponseOutputItem {
     McpCall(McpCall),
     McpListTools(McpListTools),
     McpApprovalRequest(McpApprovalRequest),
-}
+    /// An item of a type this crate does not model yet, as returned by the API.
+    Other(Value),
+}
+
+deserialize_by_type!(ResponseOutputItem {
+    "message" => Message,
+    "file_search_call" => FileSearchCall,
+    "function_call" => FunctionCall,
+    "web_search_call" => WebSearchCall,
+    "computer_call" => ComputerCall,
+    "reasoning" => Reasoning,
+    "image_generation_call" => ImageGenerationCall,
+    "code_interpreter_call" => CodeInterpreterCall,
+    "local_shell_call" => LocalShellCall,
+    "mcp_call" => McpCall,
+    "mcp_list_tools" => McpListTools,
+    "mcp_approval_request" => McpApprovalRequest,
+} else Other);
 
 /// Represents the main response from the API.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1192,7 +1423,7 @@ pub struct Response {
     pub prompt: Option<ResponsePrompt>,
     /// Configuration options for reasoning models.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reasoning: Option<Value>,
+    pub reasoning: Option<Reasoning>,
     /// Specifies the latency tier to use for processing the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_tier: Option<ServiceTier>,
@@ -1211,6 +1442,11 @@ pub struct Response {
     /// A stable identifier for your end-users.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1284,11 +1520,23 @@ pub enum ResponseFormatTextConfig {
 }
 
 /// Configuration options for a text response from the model.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ResponseTextConfig {
     /// An object specifying the format that the model must output.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<ResponseFormatTextConfig>,
+    /// Constrains the verbosity of the model's response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<Verbosity>,
+}
+
+/// How verbose the model's response should be.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verbosity {
+    Low,
+    Medium,
+    High,
 }
 
 /// A message input to the model with a role, which can be a simplified string content.
@@ -1443,6 +1691,8 @@ pub enum ResponseInputItem {
     McpApprovalResponse(ResponseInputItemMcpApprovalResponse),
     McpCall(McpCall),
     ItemReference(ResponseInputItemItemReference),
+    /// An item of a type this crate does not model yet, sent as is.
+    Other(Value),
 }
 
 /// A computer screenshot image used with the computer use tool.
@@ -1606,22 +1856,42 @@ pub enum ResponseItem {
 pub struct ResponseAudioDeltaEvent {
     pub delta: String,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the audio response is complete.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseAudioDoneEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is a partial transcript of audio.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseAudioTranscriptDeltaEvent {
     pub delta: String,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the full audio transcript is completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseAudioTranscriptDoneEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a partial code snippet is streamed by the code interpreter.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1630,6 +1900,11 @@ pub struct ResponseCodeInterpreterCallCodeDeltaEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the code snippet is finalized by the code interpreter.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1638,6 +1913,11 @@ pub struct ResponseCodeInterpreterCallCodeDoneEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the code interpreter call is completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1645,6 +1925,11 @@ pub struct ResponseCodeInterpreterCallCompletedEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a code interpreter call is in progress.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1652,6 +1937,11 @@ pub struct ResponseCodeInterpreterCallInProgressEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the code interpreter is actively interpreting the code snippet.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1659,12 +1949,22 @@ pub struct ResponseCodeInterpreterCallInterpretingEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the model response is complete.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseCompletedEvent {
     pub response: Response,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a new content part is added.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1674,6 +1974,11 @@ pub struct ResponseContentPartAddedEvent {
     pub output_index: u64,
     pub part: ResponseOutputContentChoice,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a content part is done.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1683,6 +1988,11 @@ pub struct ResponseContentPartDoneEvent {
     pub output_index: u64,
     pub part: ResponseOutputContentChoice,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// An event that is emitted when a response is created.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1690,6 +2000,11 @@ pub struct ResponseCreatedEvent {
     pub nonce: Option<String>,
     pub response: Response,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an error occurs.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1698,12 +2013,22 @@ pub struct ResponseErrorEvent {
     pub message: String,
     pub param: Option<String>,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// An event that is emitted when a response fails.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseFailedEvent {
     pub response: Response,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a file search call is completed (results found).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1711,6 +2036,11 @@ pub struct ResponseFileSearchCallCompletedEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a file search call is initiated.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1718,6 +2048,11 @@ pub struct ResponseFileSearchCallInProgressEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a file search is currently searching.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1725,6 +2060,11 @@ pub struct ResponseFileSearchCallSearchingEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is a partial function-call arguments delta.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1733,6 +2073,11 @@ pub struct ResponseFunctionCallArgumentsDeltaEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when function-call arguments are finalized.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1741,6 +2086,11 @@ pub struct ResponseFunctionCallArgumentsDoneEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an image generation tool call has completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1748,6 +2098,11 @@ pub struct ResponseImageGenCallCompletedEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an image generation tool call is actively generating an image.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1755,6 +2110,11 @@ pub struct ResponseImageGenCallGeneratingEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an image generation tool call is in progress.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1762,6 +2122,11 @@ pub struct ResponseImageGenCallInProgressEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a partial image is available during image generation streaming.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1771,18 +2136,33 @@ pub struct ResponseImageGenCallPartialImageEvent {
     pub partial_image_b64: String,
     pub partial_image_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the response is in progress.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseInProgressEvent {
     pub response: Response,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// An event that is emitted when a response finishes as incomplete.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseIncompleteEvent {
     pub response: Response,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is a delta to the arguments of an MCP tool call.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1791,6 +2171,11 @@ pub struct ResponseMcpCallArgumentsDeltaEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the arguments for an MCP tool call are finalized.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1799,16 +2184,31 @@ pub struct ResponseMcpCallArgumentsDoneEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an MCP tool call has completed successfully.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseMcpCallCompletedEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an MCP tool call has failed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseMcpCallFailedEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an MCP tool call is in progress.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1816,21 +2216,41 @@ pub struct ResponseMcpCallInProgressEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the list of available MCP tools has been successfully retrieved.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseMcpListToolsCompletedEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the attempt to list available MCP tools has failed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseMcpListToolsFailedEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the system is in the process of retrieving the list of available MCP tools.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseMcpListToolsInProgressEvent {
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a new output item is added.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1838,6 +2258,11 @@ pub struct ResponseOutputItemAddedEvent {
     pub item: ResponseOutputItem,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an output item is marked done.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1845,6 +2270,11 @@ pub struct ResponseOutputItemDoneEvent {
     pub item: ResponseOutputItem,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when an annotation is added to output text content.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1856,12 +2286,22 @@ pub struct ResponseOutputTextAnnotationAddedEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a response is queued and waiting to be processed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseQueuedEvent {
     pub response: Response,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is a delta to the reasoning content.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1871,6 +2311,11 @@ pub struct ResponseReasoningDeltaEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the reasoning content is finalized for an item.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1880,6 +2325,11 @@ pub struct ResponseReasoningDoneEvent {
     pub output_index: u64,
     pub sequence_number: u64,
     pub text: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is a delta to the reasoning summary content.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1889,6 +2339,11 @@ pub struct ResponseReasoningSummaryDeltaEvent {
     pub output_index: u64,
     pub sequence_number: u64,
     pub summary_index: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when the reasoning summary content is finalized for an item.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1898,6 +2353,11 @@ pub struct ResponseReasoningSummaryDoneEvent {
     pub sequence_number: u64,
     pub summary_index: u64,
     pub text: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a new reasoning summary part is added.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1907,6 +2367,11 @@ pub struct ResponseReasoningSummaryPartAddedEvent {
     pub part: ResponseReasoningSummaryPartAddedEventPart,
     pub sequence_number: u64,
     pub summary_index: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseReasoningSummaryPartAddedEventPart {
@@ -1920,6 +2385,11 @@ pub struct ResponseReasoningSummaryPartDoneEvent {
     pub part: ResponseReasoningSummaryPartDoneEventPart,
     pub sequence_number: u64,
     pub summary_index: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseReasoningSummaryPartDoneEventPart {
@@ -1933,6 +2403,11 @@ pub struct ResponseReasoningSummaryTextDeltaEvent {
     pub output_index: u64,
     pub sequence_number: u64,
     pub summary_index: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a reasoning summary text is completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1942,6 +2417,11 @@ pub struct ResponseReasoningSummaryTextDoneEvent {
     pub sequence_number: u64,
     pub summary_index: u64,
     pub text: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is a partial refusal text.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1951,6 +2431,11 @@ pub struct ResponseRefusalDeltaEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when refusal text is finalized.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1960,6 +2445,11 @@ pub struct ResponseRefusalDoneEvent {
     pub output_index: u64,
     pub refusal: String,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when there is an additional text delta.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1969,6 +2459,11 @@ pub struct ResponseTextDeltaEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when text content is finalized.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1978,6 +2473,11 @@ pub struct ResponseTextDoneEvent {
     pub output_index: u64,
     pub sequence_number: u64,
     pub text: String,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a web search call is completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1985,6 +2485,11 @@ pub struct ResponseWebSearchCallCompletedEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a web search call is initiated.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1992,6 +2497,11 @@ pub struct ResponseWebSearchCallInProgressEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 /// Emitted when a web search call is executing.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1999,6 +2509,11 @@ pub struct ResponseWebSearchCallSearchingEvent {
     pub item_id: String,
     pub output_index: u64,
     pub sequence_number: u64,
+    /// Fields not modeled by this crate, kept so that re-serializing is lossless. Only
+    /// filled in with the `extra-fields` feature.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 /// Represents all possible events from a response stream.
@@ -2182,14 +2697,18 @@ pub struct ResponseCreateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub background: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversation: Option<ResponseConversationParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub include: Option<Vec<ResponseIncludable>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input: Option<ResponseInputParam>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instructions: Option<String>,
+    pub instructions: Option<ResponseInstructions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_output_tokens: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tool_calls: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<ResponsesModel>,
@@ -2200,7 +2719,11 @@ pub struct ResponseCreateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt: Option<ResponsePrompt>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reasoning: Option<Value>,
+    pub prompt_cache_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<Reasoning>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_identifier: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_tier: Option<ServiceTier>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2208,6 +2731,8 @@ pub struct ResponseCreateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<ResponseStreamOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<ResponseTextConfig>,
@@ -2216,11 +2741,35 @@ pub struct ResponseCreateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<Tool>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_logprobs: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub truncation: Option<TruncationStrategy>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+    /// Additional body fields not (yet) modeled by this crate, flattened into the request.
+    /// Keys must not repeat a modeled field.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// The conversation a response belongs to. Its items are prepended to the input, and the
+/// input and output of the response are added to it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ResponseConversationParam {
+    Id(String),
+    Object { id: String },
+}
+
+/// Options for streaming responses.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResponseStreamOptions {
+    /// Whether to add random `obfuscation` padding to delta events to normalize payload
+    /// sizes against side-channel attacks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_obfuscation: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -2275,6 +2824,9 @@ pub struct ChatCompletionCreateParams {
     /// An object specifying the format that the model must output.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response_format: Option<ChatResponseFormat>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 }
 
 /// The format of the response from a chat completion.
@@ -0,0 +1,395 @@
+// src/models/vector_stores.rs
+
+use crate::models::responses::{FileSearchRanker, Filter, ListOrder, Metadata};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Set of 16 key-value pairs that can be attached to a vector store file, and used to
+/// filter search results. Values are strings, numbers or booleans.
+pub type VectorStoreFileAttributes = HashMap<String, Value>;
+
+//=======================================================================================
+// Vector Stores
+//=======================================================================================
+
+/// The status of a vector store.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreStatus {
+    Expired,
+    InProgress,
+    Completed,
+}
+
+/// The number of files in a vector store (or file batch) per processing status.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreFileCounts {
+    pub cancelled: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub in_progress: u64,
+    pub total: u64,
+}
+
+/// The expiration policy for a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreExpiresAfter {
+    /// Anchor timestamp after which the expiration policy applies. Always `last_active_at`.
+    pub anchor: String,
+    /// The number of days after the anchor time that the vector store will expire.
+    pub days: u32,
+}
+
+impl VectorStoreExpiresAfter {
+    /// Expires the vector store `days` after it was last active.
+    pub fn days_after_last_active(days: u32) -> Self {
+        Self { anchor: "last_active_at".to_string(), days }
+    }
+}
+
+/// Static chunking parameters.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StaticChunkingStrategyConfig {
+    /// The number of tokens that overlap between chunks. Must not exceed half of
+    /// `max_chunk_size_tokens`.
+    pub chunk_overlap_tokens: u32,
+    /// The maximum number of tokens in each chunk, between 100 and 4096.
+    pub max_chunk_size_tokens: u32,
+}
+
+/// The strategy used to chunk files.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChunkingStrategy {
+    /// The default strategy, currently 800 token chunks with 400 tokens of overlap.
+    Auto,
+    Static {
+        #[serde(rename = "static")]
+        config: StaticChunkingStrategyConfig,
+    },
+    /// Returned for files indexed before the `chunking_strategy` concept was introduced.
+    Other,
+}
+
+/// A collection of processed files that can be used by the `file_search` tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStore {
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the vector store was created.
+    pub created_at: i64,
+    /// The number of files in the vector store per processing status.
+    pub file_counts: VectorStoreFileCounts,
+    /// The object type, which is always `vector_store`.
+    pub object: String,
+    /// The status of the vector store.
+    pub status: VectorStoreStatus,
+    /// The total number of bytes used by the files in the vector store.
+    pub usage_bytes: u64,
+    /// The Unix timestamp (in seconds) for when the vector store was last active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_active_at: Option<i64>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The name of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The expiration policy for the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<VectorStoreExpiresAfter>,
+    /// The Unix timestamp (in seconds) for when the vector store will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
+/// Parameters for creating a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreCreateParams {
+    /// The chunking strategy used to chunk the files. Only applicable if `file_ids` is
+    /// non-empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    /// The expiration policy for the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<VectorStoreExpiresAfter>,
+    /// A list of file IDs that the vector store should use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_ids: Option<Vec<String>>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The name of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Parameters for modifying a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreUpdateParams {
+    /// The expiration policy for the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<VectorStoreExpiresAfter>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The name of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Parameters for listing vector stores.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreListParams {
+    /// A cursor for use in pagination; the ID of the last object of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination; the ID of the first object of the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// A limit on the number of objects to be returned, between 1 and 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<ListOrder>,
+}
+
+/// The result of deleting a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreDeleted {
+    pub id: String,
+    /// Whether the vector store was deleted.
+    pub deleted: bool,
+    /// The object type, which is always `vector_store.deleted`.
+    pub object: String,
+}
+
+//=======================================================================================
+// Vector Store Files
+//=======================================================================================
+
+/// The processing status of a vector store file or file batch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreFileStatus {
+    InProgress,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// The last error associated with a vector store file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreFileError {
+    /// One of `server_error`, `unsupported_file` or `invalid_file`.
+    pub code: String,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+/// A file attached to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreFile {
+    /// The ID of the file.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the vector store file was created.
+    pub created_at: i64,
+    /// The object type, which is always `vector_store.file`.
+    pub object: String,
+    /// The processing status of the file.
+    pub status: VectorStoreFileStatus,
+    /// The total vector store usage in bytes.
+    pub usage_bytes: u64,
+    /// The ID of the vector store the file is attached to.
+    pub vector_store_id: String,
+    /// The last error associated with this vector store file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<VectorStoreFileError>,
+    /// Key-value attributes attached to the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<VectorStoreFileAttributes>,
+    /// The strategy used to chunk the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+/// Parameters for attaching a file to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreFileCreateParams {
+    /// The ID of an uploaded file.
+    pub file_id: String,
+    /// Key-value attributes attached to the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<VectorStoreFileAttributes>,
+    /// The chunking strategy used to chunk the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+/// Parameters for updating the attributes of a vector store file.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreFileUpdateParams {
+    /// Key-value attributes attached to the file.
+    pub attributes: VectorStoreFileAttributes,
+}
+
+/// Parameters for listing the files of a vector store or file batch.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreFileListParams {
+    /// A cursor for use in pagination; the ID of the last object of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination; the ID of the first object of the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Only return files with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<VectorStoreFileStatus>,
+    /// A limit on the number of objects to be returned, between 1 and 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<ListOrder>,
+}
+
+/// The result of detaching a file from a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreFileDeleted {
+    pub id: String,
+    /// Whether the file was detached.
+    pub deleted: bool,
+    /// The object type, which is always `vector_store.file.deleted`.
+    pub object: String,
+}
+
+/// A chunk of parsed text from a vector store file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreFileContent {
+    /// The text content.
+    pub text: String,
+    /// The content type, which is always `text`.
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+//=======================================================================================
+// Vector Store File Batches
+//=======================================================================================
+
+/// A batch of files attached to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreFileBatch {
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the file batch was created.
+    pub created_at: i64,
+    /// The number of files in the batch per processing status.
+    pub file_counts: VectorStoreFileCounts,
+    /// The object type, which is always `vector_store.files_batch`.
+    pub object: String,
+    /// The status of the file batch.
+    pub status: VectorStoreFileStatus,
+    /// The ID of the vector store the batch belongs to.
+    pub vector_store_id: String,
+}
+
+/// Parameters for attaching a batch of files to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VectorStoreFileBatchCreateParams {
+    /// A list of uploaded file IDs.
+    pub file_ids: Vec<String>,
+    /// Key-value attributes attached to every file in the batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<VectorStoreFileAttributes>,
+    /// The chunking strategy used to chunk the files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+//=======================================================================================
+// Search
+//=======================================================================================
+
+/// A query string, or several queries to search for at once.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum VectorStoreSearchQuery {
+    String(String),
+    Multiple(Vec<String>),
+}
+
+/// Ranking options for a vector store search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct VectorStoreSearchRankingOptions {
+    /// The ranker to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranker: Option<FileSearchRanker>,
+    /// The minimum score, between 0 and 1, of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_threshold: Option<f64>,
+}
+
+/// Parameters for searching a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreSearchParams {
+    /// The query to search for.
+    pub query: VectorStoreSearchQuery,
+    /// A filter to apply based on file attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Filter>,
+    /// The maximum number of results to return, between 1 and 50.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+    /// Ranking options for the search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_options: Option<VectorStoreSearchRankingOptions>,
+    /// Whether to rewrite the natural language query for vector search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite_query: Option<bool>,
+}
+
+impl VectorStoreSearchParams {
+    /// Creates search parameters for a single query string.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: VectorStoreSearchQuery::String(query.into()),
+            filters: None,
+            max_num_results: None,
+            ranking_options: None,
+            rewrite_query: None,
+        }
+    }
+}
+
+/// A single result of a vector store search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreSearchResult {
+    /// Key-value attributes attached to the file.
+    pub attributes: Option<VectorStoreFileAttributes>,
+    /// The matching chunks of the file.
+    pub content: Vec<VectorStoreFileContent>,
+    /// The ID of the file.
+    pub file_id: String,
+    /// The name of the file.
+    pub filename: String,
+    /// The similarity score of the result.
+    pub score: f64,
+}
+
+/// A page of vector store search results.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VectorStoreSearchResultsPage {
+    pub data: Vec<VectorStoreSearchResult>,
+    /// Whether there are more results.
+    #[serde(default)]
+    pub has_more: bool,
+    /// The cursor of the next page, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+    /// The object type, which is always `vector_store.search_results.page`.
+    pub object: String,
+    /// The query used for the search, after any rewriting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_query: Option<VectorStoreSearchQuery>,
+}
@@ -0,0 +1,126 @@
+// src/moderation.rs
+
+//! Pre-flight moderation of user input.
+//!
+//! A [`ModerationGuard`] attached to a [`Client`](crate::client::Client) classifies the
+//! user-role text and images of every [`ResponseCreateParams`] with the Moderations API
+//! before the request is sent, and refuses flagged input with
+//! [`Error::ModerationFlagged`](crate::client::Error::ModerationFlagged).
+
+use crate::models::moderations::{
+    ModerationCreateParams, ModerationCreateResponse, ModerationImageUrl, ModerationInput,
+    ModerationMultiModalInput,
+};
+use crate::models::responses::{
+    EasyInputMessageContent, MessageRole, ResponseCreateParams, ResponseInputContent,
+    ResponseInputItem, ResponseInputParam,
+};
+
+/// Decides which requests are screened and what counts as flagged.
+#[derive(Debug, Clone, Default)]
+pub struct ModerationGuard {
+    model: Option<String>,
+    score_threshold: Option<f64>,
+}
+
+impl ModerationGuard {
+    /// Creates a guard that uses the default moderation model and rejects input the API
+    /// flags.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the moderation model, e.g. `omni-moderation-latest`. Image inputs are only
+    /// screened by omni models.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Also rejects input when any category scores at or above `threshold`, even if the
+    /// API did not flag it.
+    pub fn with_score_threshold(mut self, threshold: f64) -> Self {
+        self.score_threshold = Some(threshold);
+        self
+    }
+
+    /// Builds the moderation request for the user input of `params`, or `None` if there is
+    /// no user input to screen.
+    pub fn params_for(&self, params: &ResponseCreateParams) -> Option<ModerationCreateParams> {
+        let inputs = user_inputs(params.input.as_ref()?);
+        if inputs.is_empty() {
+            return None;
+        }
+        // Legacy text models only accept plain strings.
+        let input = if self.model.as_deref().is_some_and(|model| model.starts_with("text-moderation")) {
+            let texts: Vec<String> = inputs
+                .into_iter()
+                .filter_map(|input| match input {
+                    ModerationMultiModalInput::Text { text } => Some(text),
+                    ModerationMultiModalInput::ImageUrl { .. } => None,
+                })
+                .collect();
+            if texts.is_empty() {
+                return None;
+            }
+            ModerationInput::StringArray(texts)
+        } else {
+            ModerationInput::MultiModal(inputs)
+        };
+        Some(ModerationCreateParams { input, model: self.model.clone() })
+    }
+
+    /// The categories that cause `moderation` to be rejected; empty if it passes.
+    pub fn violations(&self, moderation: &ModerationCreateResponse) -> Vec<String> {
+        let mut categories: Vec<String> = Vec::new();
+        for result in &moderation.results {
+            let mut flagged = result.categories.flagged();
+            if flagged.is_empty()
+                && (result.flagged
+                    || self.score_threshold.is_some_and(|threshold| result.category_scores.max() >= threshold))
+            {
+                flagged.push("score_threshold");
+            }
+            for category in flagged {
+                if !categories.iter().any(|c| c == category) {
+                    categories.push(category.to_string());
+                }
+            }
+        }
+        categories
+    }
+}
+
+fn user_inputs(input: &ResponseInputParam) -> Vec<ModerationMultiModalInput> {
+    let items = match input {
+        ResponseInputParam::String(text) => return vec![ModerationMultiModalInput::Text { text: text.clone() }],
+        ResponseInputParam::Items(items) => items,
+    };
+
+    let mut inputs = Vec::new();
+    for item in items {
+        match item {
+            ResponseInputItem::EasyMessage(message) if message.role == MessageRole::User => match &message.content {
+                EasyInputMessageContent::String(text) => {
+                    inputs.push(ModerationMultiModalInput::Text { text: text.clone() })
+                }
+                EasyInputMessageContent::List(content) => inputs.extend(content.iter().filter_map(content_input)),
+            },
+            ResponseInputItem::Message(message) if message.role == MessageRole::User => {
+                inputs.extend(message.content.iter().filter_map(content_input))
+            }
+            _ => {}
+        }
+    }
+    inputs
+}
+
+fn content_input(content: &ResponseInputContent) -> Option<ModerationMultiModalInput> {
+    match content {
+        ResponseInputContent::InputText(text) => Some(ModerationMultiModalInput::Text { text: text.text.clone() }),
+        ResponseInputContent::InputImage(image) => image.image_url.as_ref().map(|url| {
+            ModerationMultiModalInput::ImageUrl { image_url: ModerationImageUrl { url: url.clone() } }
+        }),
+        ResponseInputContent::InputFile(_) => None,
+    }
+}
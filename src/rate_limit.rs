@@ -0,0 +1,297 @@
+// src/rate_limit.rs
+
+//! A client-side token-bucket rate limiter that calibrates itself from the
+//! `x-ratelimit-*` response headers.
+//!
+//! Requests and tokens are tracked per model. Callers that would exceed a bucket are
+//! queued in arrival order until capacity frees up, instead of being sent and answered
+//! with `429 Too Many Requests`.
+
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const LIMIT_REQUESTS: &str = "x-ratelimit-limit-requests";
+const LIMIT_TOKENS: &str = "x-ratelimit-limit-tokens";
+const REMAINING_REQUESTS: &str = "x-ratelimit-remaining-requests";
+const REMAINING_TOKENS: &str = "x-ratelimit-remaining-tokens";
+const RESET_REQUESTS: &str = "x-ratelimit-reset-requests";
+const RESET_TOKENS: &str = "x-ratelimit-reset-tokens";
+
+/// The default number of times a request rejected with `429` is re-queued.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// The longest delay before re-queuing a `429` response that has no `retry-after` header.
+pub const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(64);
+
+/// The delay before retry number `attempt` (starting at 1) when the API gives none:
+/// doubling from two seconds, capped at [`MAX_RETRY_BACKOFF`].
+pub fn retry_backoff(attempt: u32) -> Duration {
+    Duration::from_secs(1u64.checked_shl(attempt).unwrap_or(u64::MAX)).min(MAX_RETRY_BACKOFF)
+}
+
+/// Initial per-minute limits, used until the API reports the real ones.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RateLimits {
+    /// Requests per minute. Unlimited when unset.
+    pub requests_per_minute: Option<f64>,
+    /// Tokens per minute. Unlimited when unset.
+    pub tokens_per_minute: Option<f64>,
+}
+
+impl RateLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn requests_per_minute(mut self, limit: f64) -> Self {
+        self.requests_per_minute = Some(limit);
+        self
+    }
+
+    pub fn tokens_per_minute(mut self, limit: f64) -> Self {
+        self.tokens_per_minute = Some(limit);
+        self
+    }
+}
+
+/// A single token bucket refilled continuously over one minute.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    capacity: f64,
+    available: f64,
+}
+
+impl Bucket {
+    fn new(capacity: f64) -> Self {
+        Self { capacity, available: capacity }
+    }
+
+    fn refill(&mut self, elapsed: Duration) {
+        let per_second = self.capacity / 60.0;
+        self.available = (self.available + per_second * elapsed.as_secs_f64()).min(self.capacity);
+    }
+
+    /// How long until `amount` can be taken from the bucket.
+    fn wait_for(&self, amount: f64) -> Duration {
+        // Requests larger than the whole bucket only wait for a full bucket.
+        let amount = amount.min(self.capacity);
+        if self.available >= amount || self.capacity <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((amount - self.available) * 60.0 / self.capacity)
+        }
+    }
+
+    /// Applies reported limits, returning how long to block if the bucket is exhausted.
+    fn calibrate(&mut self, limit: Option<f64>, remaining: Option<f64>, reset: Option<Duration>) -> Option<Duration> {
+        if let Some(limit) = limit {
+            self.capacity = limit;
+        }
+        let remaining = remaining?;
+        self.available = remaining.min(self.capacity);
+        if remaining <= 0.0 { reset } else { None }
+    }
+}
+
+#[derive(Debug)]
+struct ModelState {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+    blocked_until: Option<Instant>,
+    last_refill: Instant,
+}
+
+impl ModelState {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.last_refill = now;
+        for bucket in [&mut self.requests, &mut self.tokens].into_iter().flatten() {
+            bucket.refill(elapsed);
+        }
+        if self.blocked_until.is_some_and(|until| until <= now) {
+            self.blocked_until = None;
+        }
+    }
+
+    fn block_for(&mut self, duration: Duration, now: Instant) {
+        let until = now + duration;
+        self.blocked_until = Some(self.blocked_until.map_or(until, |current| current.max(until)));
+    }
+
+    fn wait_for(&self, tokens: f64, now: Instant) -> Duration {
+        let blocked = self
+            .blocked_until
+            .map(|until| until.saturating_duration_since(now))
+            .unwrap_or_default();
+        [(&self.requests, 1.0), (&self.tokens, tokens)]
+            .into_iter()
+            .filter_map(|(bucket, amount)| bucket.as_ref().map(|b| b.wait_for(amount)))
+            .fold(blocked, Duration::max)
+    }
+}
+
+#[derive(Debug)]
+struct ModelLimiter {
+    /// Serializes waiters per model. `tokio::sync::Mutex` is FIFO, so callers are
+    /// served in the order they arrived.
+    queue: tokio::sync::Mutex<()>,
+    state: Mutex<ModelState>,
+}
+
+/// A per-model token-bucket rate limiter.
+#[derive(Debug)]
+pub struct RateLimiter {
+    defaults: RateLimits,
+    max_retries: u32,
+    models: Mutex<HashMap<String, Arc<ModelLimiter>>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimits::default())
+    }
+}
+
+impl RateLimiter {
+    /// Creates a limiter that starts every model with the given limits.
+    ///
+    /// With the default (empty) limits, nothing is throttled until the API reports
+    /// limits through its response headers.
+    pub fn new(defaults: RateLimits) -> Self {
+        Self { defaults, max_retries: DEFAULT_MAX_RETRIES, models: Mutex::new(HashMap::new()) }
+    }
+
+    /// Sets how many times a request rejected with `429` is re-queued before the error is
+    /// returned to the caller.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The number of times a request rejected with `429` is re-queued.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    fn model(&self, model: &str) -> Arc<ModelLimiter> {
+        let mut models = self.models.lock().unwrap_or_else(|e| e.into_inner());
+        models
+            .entry(model.to_string())
+            .or_insert_with(|| {
+                Arc::new(ModelLimiter {
+                    queue: tokio::sync::Mutex::new(()),
+                    state: Mutex::new(ModelState {
+                        requests: self.defaults.requests_per_minute.map(Bucket::new),
+                        tokens: self.defaults.tokens_per_minute.map(Bucket::new),
+                        blocked_until: None,
+                        last_refill: Instant::now(),
+                    }),
+                })
+            })
+            .clone()
+    }
+
+    /// Waits until one request of `tokens` estimated tokens may be sent to `model`, then
+    /// takes it from the buckets.
+    pub async fn acquire(&self, model: &str, tokens: u64) {
+        let limiter = self.model(model);
+        let _turn = limiter.queue.lock().await;
+        loop {
+            let wait = {
+                let mut state = limiter.state.lock().unwrap_or_else(|e| e.into_inner());
+                let now = Instant::now();
+                state.refill(now);
+                let wait = state.wait_for(tokens as f64, now);
+                if wait.is_zero() {
+                    if let Some(bucket) = &mut state.requests {
+                        bucket.available -= 1.0;
+                    }
+                    if let Some(bucket) = &mut state.tokens {
+                        bucket.available -= tokens as f64;
+                    }
+                }
+                wait
+            };
+            if wait.is_zero() {
+                return;
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Calibrates the buckets of `model` from the `x-ratelimit-*` headers of a response.
+    pub fn observe(&self, model: &str, headers: &HeaderMap) {
+        let number = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<f64>().ok();
+        let duration = |name: &str| parse_reset(headers.get(name)?.to_str().ok()?);
+
+        let limiter = self.model(model);
+        let mut guard = limiter.state.lock().unwrap_or_else(|e| e.into_inner());
+        let state = &mut *guard;
+        let now = Instant::now();
+        state.refill(now);
+        let mut block = None;
+        for (bucket, limit, remaining, reset) in [
+            (&mut state.requests, LIMIT_REQUESTS, REMAINING_REQUESTS, RESET_REQUESTS),
+            (&mut state.tokens, LIMIT_TOKENS, REMAINING_TOKENS, RESET_TOKENS),
+        ] {
+            let limit = number(limit);
+            if bucket.is_none()
+                && let Some(limit) = limit
+            {
+                *bucket = Some(Bucket::new(limit));
+            }
+            if let Some(bucket) = bucket
+                && let Some(duration) = bucket.calibrate(limit, number(remaining), duration(reset))
+            {
+                block = block.max(Some(duration));
+            }
+        }
+        if let Some(duration) = block {
+            state.block_for(duration, now);
+        }
+    }
+
+    /// Blocks `model` for the given duration, e.g. after a `429` without reset headers.
+    pub fn back_off(&self, model: &str, duration: Duration) {
+        let limiter = self.model(model);
+        let mut state = limiter.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.block_for(duration, Instant::now());
+    }
+
+    /// Estimates the tokens a request will consume: roughly four bytes of serialized
+    /// request per input token, plus the requested output budget.
+    pub fn estimate_tokens(body: &impl Serialize, max_output_tokens: Option<i64>) -> u64 {
+        let input = serde_json::to_vec(body).map(|b| b.len() as u64 / 4).unwrap_or(0);
+        input + max_output_tokens.unwrap_or(0).max(0) as u64
+    }
+}
+
+/// Parses a reset duration as sent by the API, e.g. `"1s"`, `"6m0s"`, `"20ms"` or
+/// `"1h2m3.5s"`.
+pub fn parse_reset(value: &str) -> Option<Duration> {
+    let mut rest = value.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total = 0.0;
+    while !rest.is_empty() {
+        let number_len = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+        let number: f64 = rest[..number_len].parse().ok()?;
+        rest = &rest[number_len..];
+        let unit_len = rest.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(rest.len());
+        let seconds = match &rest[..unit_len] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            _ => return None,
+        };
+        total += number * seconds;
+        rest = &rest[unit_len..];
+    }
+    Some(Duration::from_secs_f64(total))
+}
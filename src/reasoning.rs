@@ -0,0 +1,74 @@
+// src/reasoning.rs
+
+//! Stateless multi-turn reasoning.
+//!
+//! With `store: false` the API keeps no conversation state, so `previous_response_id`
+//! cannot be used and the model's reasoning would be lost between turns. Instead, request
+//! the reasoning as encrypted content with [`request_encrypted_reasoning`], and pass each
+//! response's output back as input to the next turn with [`append_output`]. Encrypted
+//! reasoning items are opaque and can only be decrypted by the API.
+
+use crate::models::responses::{
+    EasyInputMessage, Response, ResponseCreateParams, ResponseIncludable, ResponseInputItem,
+    ResponseInputParam, ResponseOutputItem, ResponseReasoningItem,
+};
+
+/// Configures `params` for stateless reasoning: disables storage and asks for the
+/// reasoning items to be returned with their encrypted content.
+pub fn request_encrypted_reasoning(params: &mut ResponseCreateParams) {
+    params.store = Some(false);
+    let include = params.include.get_or_insert_with(Vec::new);
+    if !include.contains(&ResponseIncludable::ReasoningEncryptedContent) {
+        include.push(ResponseIncludable::ReasoningEncryptedContent);
+    }
+}
+
+/// The reasoning items of a response that carry encrypted content.
+pub fn encrypted_reasoning_items(response: &Response) -> Vec<&ResponseReasoningItem> {
+    response
+        .output
+        .iter()
+        .filter_map(|item| match item {
+            ResponseOutputItem::Reasoning(reasoning) if reasoning.encrypted_content.is_some() => Some(reasoning),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Converts the output of a response into input items for the next turn, preserving
+/// reasoning items (and their encrypted content), messages and tool calls in order.
+pub fn output_to_input(output: &[ResponseOutputItem]) -> Vec<ResponseInputItem> {
+    output
+        .iter()
+        .map(|item| match item.clone() {
+            ResponseOutputItem::Message(message) => ResponseInputItem::OutputMessage(message),
+            ResponseOutputItem::FileSearchCall(call) => ResponseInputItem::FileSearchCall(call),
+            ResponseOutputItem::FunctionCall(call) => ResponseInputItem::FunctionCall(call),
+            ResponseOutputItem::WebSearchCall(call) => ResponseInputItem::WebSearchCall(call),
+            ResponseOutputItem::ComputerCall(call) => ResponseInputItem::ComputerCall(call),
+            ResponseOutputItem::Reasoning(reasoning) => ResponseInputItem::Reasoning(reasoning),
+            ResponseOutputItem::ImageGenerationCall(call) => ResponseInputItem::ImageGenerationCall(call),
+            ResponseOutputItem::CodeInterpreterCall(call) => ResponseInputItem::CodeInterpreterCall(call),
+            ResponseOutputItem::LocalShellCall(call) => ResponseInputItem::LocalShellCall(call),
+            ResponseOutputItem::McpCall(call) => ResponseInputItem::McpCall(call),
+            ResponseOutputItem::McpListTools(list) => ResponseInputItem::McpListTools(list),
+            ResponseOutputItem::McpApprovalRequest(request) => ResponseInputItem::McpApprovalRequest(request),
+            ResponseOutputItem::Other(item) => ResponseInputItem::Other(item),
+        })
+        .collect()
+}
+
+/// Threads the output of `response` into `params` so the next turn continues from it.
+///
+/// A string input is first converted into a user message, so the resulting input reads
+/// as the previous input, the response output, then anything appended afterwards (such as
+/// the next user message or function call outputs).
+pub fn append_output(params: &mut ResponseCreateParams, response: &Response) {
+    let mut items = match params.input.take() {
+        None => Vec::new(),
+        Some(ResponseInputParam::Items(items)) => items,
+        Some(ResponseInputParam::String(text)) => vec![EasyInputMessage::user(text).into()],
+    };
+    items.extend(output_to_input(&response.output));
+    params.input = Some(ResponseInputParam::Items(items));
+}
@@ -0,0 +1,249 @@
+// src/testing.rs
+
+//! A local mock of the OpenAI API for tests, enabled by the `testing` feature.
+//!
+//! [`MockServer`] listens on `127.0.0.1` and serves `POST /responses`,
+//! `GET /responses/{id}` and `POST /chat/completions` with replies scripted per route.
+//! Every request is recorded for assertions, and replies can be delayed, cut off
+//! mid-stream or answered with error statuses:
+//!
+//! ```no_run
+//! # async fn example() {
+//! use sh_openai_responses::testing::{MockReply, MockRoute, MockServer};
+//!
+//! let server = MockServer::start().await;
+//! server.enqueue(MockRoute::CreateResponse, MockReply::error(429, "rate_limit_exceeded", "Slow down"));
+//! let client = server.client();
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::transport::RecordedRequest;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::response::Response;
+use axum::Router;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+//=======================================================================================
+// Routes & Replies
+//=======================================================================================
+
+/// An endpoint served by the [`MockServer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MockRoute {
+    /// `POST /responses`
+    CreateResponse,
+    /// `GET /responses/{id}`
+    RetrieveResponse,
+    /// `POST /chat/completions`
+    ChatCompletion,
+}
+
+impl MockRoute {
+    fn of(method: &Method, path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.as_slice()) {
+            (&Method::POST, ["responses"]) => Some(MockRoute::CreateResponse),
+            (&Method::GET, ["responses", _]) => Some(MockRoute::RetrieveResponse),
+            (&Method::POST, ["chat", "completions"]) => Some(MockRoute::ChatCompletion),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MockBody {
+    Json(Value),
+    Sse { events: Vec<Value>, disconnect_after: Option<usize> },
+}
+
+/// A scripted reply to one request.
+#[derive(Debug, Clone)]
+pub struct MockReply {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: MockBody,
+    delay: Duration,
+}
+
+impl MockReply {
+    /// A `200 OK` JSON reply.
+    pub fn json(body: &impl Serialize) -> Self {
+        let body = serde_json::to_value(body).expect("mock reply body must serialize");
+        MockReply { status: StatusCode::OK, headers: HeaderMap::new(), body: MockBody::Json(body), delay: Duration::ZERO }
+    }
+
+    /// A `200 OK` Server-Sent Events reply, one event per item. Events should carry their
+    /// `type`, as [`ResponseStreamEvent`](crate::models::responses::ResponseStreamEvent)s do.
+    pub fn sse<E: Serialize>(events: impl IntoIterator<Item = E>) -> Self {
+        let events = events
+            .into_iter()
+            .map(|event| serde_json::to_value(event).expect("mock stream event must serialize"))
+            .collect();
+        MockReply {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: MockBody::Sse { events, disconnect_after: None },
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// An API error reply with the given status, error code and message.
+    pub fn error(status: u16, code: &str, message: &str) -> Self {
+        let status = StatusCode::from_u16(status).expect("invalid mock status code");
+        MockReply { status, ..Self::json(&serde_json::json!({ "code": code, "message": message })) }
+    }
+
+    /// Waits `delay` before replying.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Adds a response header.
+    pub fn with_header(mut self, name: &'static str, value: &str) -> Self {
+        let value = HeaderValue::from_str(value).expect("invalid mock header value");
+        self.headers.insert(HeaderName::from_static(name), value);
+        self
+    }
+
+    /// Drops the connection after sending `events` stream events (or, for a JSON reply,
+    /// after the headers), as a flaky network would.
+    pub fn disconnect_after(mut self, events: usize) -> Self {
+        self.body = match self.body {
+            MockBody::Sse { events: all, .. } => MockBody::Sse { events: all, disconnect_after: Some(events) },
+            MockBody::Json(_) => MockBody::Sse { events: Vec::new(), disconnect_after: Some(0) },
+        };
+        self
+    }
+
+    fn into_response(self) -> Response {
+        let (content_type, body) = match self.body {
+            MockBody::Json(value) => ("application/json", Body::from(value.to_string())),
+            MockBody::Sse { events, disconnect_after } => {
+                let sent = disconnect_after.unwrap_or(events.len()).min(events.len());
+                let chunks: Vec<io::Result<Bytes>> = events[..sent]
+                    .iter()
+                    .map(|event| {
+                        let name = event.get("type").and_then(Value::as_str).unwrap_or("message");
+                        Ok(Bytes::from(format!("event: {name}\ndata: {event}\n\n")))
+                    })
+                    .collect();
+                // Yield before failing so the events already sent are flushed to the client.
+                let disconnect = futures_util::stream::iter(disconnect_after).then(|_| async {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    Err(io::Error::new(io::ErrorKind::ConnectionReset, "mock disconnect"))
+                });
+                let stream = futures_util::stream::iter(chunks).chain(disconnect);
+                ("text/event-stream", Body::from_stream(stream))
+            }
+        };
+        let mut response = Response::new(body);
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response.headers_mut().insert("content-type", HeaderValue::from_static(content_type));
+        response
+    }
+}
+
+//=======================================================================================
+// Server
+//=======================================================================================
+
+#[derive(Debug, Default)]
+struct MockState {
+    replies: Mutex<HashMap<MockRoute, VecDeque<MockReply>>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+/// A local mock OpenAI server. It shuts down when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Starts a server on a free port of `127.0.0.1`.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("failed to bind mock server");
+        let addr = listener.local_addr().expect("mock server has no local address");
+        let state = Arc::new(MockState::default());
+        let (shutdown, stopped) = oneshot::channel::<()>();
+
+        let app = Router::new().fallback(handle).with_state(state.clone());
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app)
+                .with_graceful_shutdown(async {
+                    let _ = stopped.await;
+                })
+                .await;
+        });
+        MockServer { addr, state, shutdown: Some(shutdown) }
+    }
+
+    /// The base URL to point a client at, e.g. `http://127.0.0.1:49152`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// A client pointed at this server.
+    pub fn client(&self) -> Client {
+        Client::new_with_base_url("sk-mock".to_string(), self.base_url())
+    }
+
+    /// Queues a reply for the next request to `route`. Replies to a route are used in the
+    /// order they were queued; a route with no replies left answers `404`.
+    pub fn enqueue(&self, route: MockRoute, reply: MockReply) {
+        self.state.replies.lock().unwrap().entry(route).or_default().push_back(reply);
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+async fn handle(State(state): State<Arc<MockState>>, request: Request) -> Response {
+    let (parts, body) = request.into_parts();
+    let body = axum::body::to_bytes(body, usize::MAX).await.unwrap_or_default();
+    let url = format!("http://mock{}", parts.uri).parse().expect("mock request URI is a valid URL");
+    state.requests.lock().unwrap().push(RecordedRequest {
+        method: parts.method.clone(),
+        url,
+        headers: parts.headers,
+        body,
+    });
+
+    let reply = MockRoute::of(&parts.method, parts.uri.path())
+        .and_then(|route| state.replies.lock().unwrap().get_mut(&route)?.pop_front());
+    let reply = reply.unwrap_or_else(|| {
+        let message = format!("No mock reply queued for {} {}", parts.method, parts.uri.path());
+        MockReply::error(404, "not_found", &message)
+    });
+    if !reply.delay.is_zero() {
+        tokio::time::sleep(reply.delay).await;
+    }
+    reply.into_response()
+}
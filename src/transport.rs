@@ -0,0 +1,127 @@
+// src/transport.rs
+
+//! Pluggable HTTP transports.
+//!
+//! The [`Client`](crate::client::Client) builds each request with `reqwest` and hands it
+//! to its [`HttpTransport`] to be sent. [`ReqwestTransport`] is the default; attach an
+//! [`InMemoryTransport`] with `Client::with_transport` to answer requests from a closure
+//! without opening a socket, or implement the trait over another HTTP stack (`reqwest`
+//! requests and responses convert to and from `http` types). The [`vcr`] module records
+//! and replays interactions through another transport.
+
+use crate::client::Result;
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use http_body_util::BodyExt;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Request, Response, Url};
+use std::fmt;
+use std::sync::Mutex;
+
+pub mod vcr;
+
+/// Sends HTTP requests on behalf of the client.
+pub trait HttpTransport: Send + Sync {
+    /// Sends `request` and returns the response once its headers have arrived.
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+impl fmt::Debug for dyn HttpTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HttpTransport")
+    }
+}
+
+//=======================================================================================
+// Reqwest Transport
+//=======================================================================================
+
+/// Sends requests with a [`reqwest::Client`].
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    http_client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport that sends requests with `http_client`.
+    pub fn new(http_client: reqwest::Client) -> Self {
+        ReqwestTransport { http_client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move { Ok(self.http_client.execute(request).await?) })
+    }
+}
+
+//=======================================================================================
+// In-Memory Transport
+//=======================================================================================
+
+/// A request received by an [`InMemoryTransport`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// The request body. Streaming bodies, such as multipart uploads, are read in full.
+    pub body: Bytes,
+}
+
+impl RecordedRequest {
+    /// Parses the body as JSON.
+    pub fn json(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+type Handler = Box<dyn Fn(&RecordedRequest) -> Response + Send + Sync>;
+
+/// Answers requests with a handler function and records them, without any network.
+pub struct InMemoryTransport {
+    handler: Handler,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl InMemoryTransport {
+    /// Creates a transport that answers every request with `handler`. Responses can be
+    /// built with [`canned_response`](crate::interceptor::canned_response).
+    pub fn new(handler: impl Fn(&RecordedRequest) -> Response + Send + Sync + 'static) -> Self {
+        InMemoryTransport { handler: Box::new(handler), requests: Mutex::new(Vec::new()) }
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HttpTransport for InMemoryTransport {
+    fn send(&self, mut request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move {
+            let body = match request.body_mut().take() {
+                Some(body) => match body.as_bytes() {
+                    Some(bytes) => Bytes::copy_from_slice(bytes),
+                    None => body.collect().await?.to_bytes(),
+                },
+                None => Bytes::new(),
+            };
+            let recorded = RecordedRequest {
+                body,
+                method: request.method().clone(),
+                url: request.url().clone(),
+                headers: request.headers().clone(),
+            };
+            let response = (self.handler)(&recorded);
+            self.requests.lock().unwrap().push(recorded);
+            Ok(response)
+        })
+    }
+}
+
+impl fmt::Debug for InMemoryTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryTransport").field("requests", &self.requests).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,523 @@
+// src/transport/vcr.rs
+
+//! Record-and-replay of HTTP interactions ("cassettes") for deterministic tests.
+//!
+//! A [`VcrTransport`] in record mode sends requests with an inner transport and writes
+//! each request/response pair to a JSON or YAML cassette; in replay mode it answers requests from
+//! the cassette without any network. Streaming responses are recorded event by event with
+//! their timing. Secrets and PHI are removed with a [`Redactor`] before anything is
+//! written, and requests are matched by method, path and their redacted JSON body:
+//!
+//! ```no_run
+//! # fn example() -> std::io::Result<()> {
+//! use sh_openai_responses::client::Client;
+//! use sh_openai_responses::transport::vcr::{Redactor, VcrMode, VcrTransport};
+//! use std::sync::Arc;
+//!
+//! let client = Client::new(std::env::var("OPENAI_API_KEY").unwrap_or_default());
+//! let vcr = VcrTransport::new("tests/cassettes/triage.json", VcrMode::Auto, client.transport().clone())?
+//!     .with_redactor(Redactor::new().redact_field("patient_name").redact_text("Jane Doe"));
+//! let client = client.with_transport(Arc::new(vcr));
+//! # Ok(())
+//! # }
+//! ```
+
+use super::HttpTransport;
+use crate::client::{Error, Result};
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use futures_util::StreamExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const REDACTED: &str = "[REDACTED]";
+
+//=======================================================================================
+// Cassettes
+//=======================================================================================
+
+/// A recorded set of HTTP interactions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Reads a cassette from a file, as YAML if its extension is `yaml` or `yml` and as
+    /// JSON otherwise.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read(path)?;
+        if is_yaml(path) {
+            serde_yaml::from_slice(&contents).map_err(invalid_data)
+        } else {
+            serde_json::from_slice(&contents).map_err(invalid_data)
+        }
+    }
+
+    /// Writes the cassette to a file, in the format [`Cassette::load`] picks for its path,
+    /// creating parent directories as needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = if is_yaml(path) {
+            serde_yaml::to_string(self).map_err(invalid_data)?.into_bytes()
+        } else {
+            serde_json::to_vec_pretty(self).map_err(invalid_data)?
+        };
+        std::fs::write(path, contents)
+    }
+}
+
+fn is_yaml(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| matches!(ext, "yaml" | "yml"))
+}
+
+fn invalid_data(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// A request and the response it received.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Interaction {
+    pub request: CassetteRequest,
+    pub response: CassetteResponse,
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CassetteRequest {
+    pub method: String,
+    /// The path and query, without the base URL.
+    pub path: String,
+    pub headers: BTreeMap<String, String>,
+    /// The body: parsed JSON, a string for other text, or null when empty.
+    pub body: Value,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CassetteResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    /// The body, as a single chunk or one chunk per Server-Sent Event.
+    pub chunks: Vec<CassetteChunk>,
+}
+
+/// A piece of a recorded response body. Bodies are stored as (lossy) UTF-8 text.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CassetteChunk {
+    /// Milliseconds since the previous chunk (or the response headers).
+    pub delay_ms: u64,
+    pub data: String,
+}
+
+//=======================================================================================
+// Redaction
+//=======================================================================================
+
+type Filter = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Removes secrets and PHI from interactions before they are written or matched.
+///
+/// Redacted values are replaced with `[REDACTED]`. By default the `authorization`,
+/// `cookie`, `set-cookie`, `openai-organization` and `openai-project` headers are redacted.
+#[derive(Clone)]
+pub struct Redactor {
+    headers: Vec<String>,
+    fields: Vec<String>,
+    texts: Vec<String>,
+    filters: Vec<Filter>,
+}
+
+impl Redactor {
+    /// A redactor with the default headers.
+    pub fn new() -> Self {
+        let headers = ["authorization", "cookie", "set-cookie", "openai-organization", "openai-project"];
+        Redactor {
+            headers: headers.map(String::from).to_vec(),
+            fields: Vec::new(),
+            texts: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Also redacts the header `name`.
+    pub fn redact_header(mut self, name: impl Into<String>) -> Self {
+        self.headers.push(name.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Redacts the value of every JSON object field called `name`, at any depth of
+    /// request and response bodies (including stream events).
+    pub fn redact_field(mut self, name: impl Into<String>) -> Self {
+        self.fields.push(name.into());
+        self
+    }
+
+    /// Redacts every occurrence of `text` in bodies, e.g. a patient's name.
+    pub fn redact_text(mut self, text: impl Into<String>) -> Self {
+        self.texts.push(text.into());
+        self
+    }
+
+    /// Passes every body through `filter`, e.g. to mask identifiers with a regex.
+    pub fn with_filter(mut self, filter: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        self.filters.push(Arc::new(filter));
+        self
+    }
+
+    fn headers(&self, headers: &HeaderMap) -> BTreeMap<String, String> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.headers.iter().any(|h| h == name.as_str()) {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.as_str().to_string(), value)
+            })
+            .collect()
+    }
+
+    fn text(&self, text: &str) -> String {
+        let mut text = self.texts.iter().fold(text.to_string(), |text, secret| text.replace(secret.as_str(), REDACTED));
+        for filter in &self.filters {
+            text = filter(&text);
+        }
+        text
+    }
+
+    fn value(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.fields.contains(key) {
+                        *value = Value::String(REDACTED.to_string());
+                    } else {
+                        self.value(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.value(item)),
+            Value::String(text) => *text = self.text(text),
+            _ => {}
+        }
+    }
+
+    /// Redacts a request body into its normalized form for storage and matching.
+    fn body(&self, body: &[u8]) -> Value {
+        if body.is_empty() {
+            return Value::Null;
+        }
+        match serde_json::from_slice::<Value>(body) {
+            Ok(mut value) => {
+                self.value(&mut value);
+                value
+            }
+            Err(_) => Value::String(self.text(&String::from_utf8_lossy(body))),
+        }
+    }
+
+    /// Redacts a response chunk: JSON bodies and the `data:` lines of stream events.
+    fn chunk(&self, chunk: &str) -> String {
+        let json = |text: &str| {
+            let mut value = serde_json::from_str::<Value>(text).ok()?;
+            self.value(&mut value);
+            Some(value.to_string())
+        };
+        if let Some(redacted) = json(chunk) {
+            return redacted;
+        }
+        let lines: Vec<String> = chunk
+            .split('\n')
+            .map(|line| match line.strip_prefix("data: ").and_then(json) {
+                Some(redacted) => format!("data: {redacted}"),
+                None => self.text(line),
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Redactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redactor")
+            .field("headers", &self.headers)
+            .field("fields", &self.fields)
+            .field("texts", &self.texts.len())
+            .field("filters", &self.filters.len())
+            .finish()
+    }
+}
+
+//=======================================================================================
+// Transport
+//=======================================================================================
+
+/// Whether a [`VcrTransport`] records or replays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Sends every request and records it, replacing any existing cassette. Response bodies
+    /// pass through as they arrive; an interaction is written once its body has been read
+    /// to the end.
+    Record,
+    /// Answers every request from the cassette; unmatched requests fail.
+    Replay,
+    /// Replays if the cassette exists, and records otherwise.
+    Auto,
+}
+
+/// A transport that records interactions to, or replays them from, a cassette.
+#[derive(Debug)]
+pub struct VcrTransport {
+    path: PathBuf,
+    recording: bool,
+    inner: Arc<dyn HttpTransport>,
+    redactor: Redactor,
+    replay_timing: bool,
+    cassette: Arc<Mutex<Cassette>>,
+    save_lock: Arc<tokio::sync::Mutex<()>>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl VcrTransport {
+    /// Creates a transport for the cassette at `path`. `inner` sends requests while
+    /// recording. Fails if a cassette to replay cannot be read.
+    pub fn new(path: impl Into<PathBuf>, mode: VcrMode, inner: Arc<dyn HttpTransport>) -> io::Result<Self> {
+        let path = path.into();
+        let recording = match mode {
+            VcrMode::Record => true,
+            VcrMode::Replay => false,
+            VcrMode::Auto => !path.exists(),
+        };
+        let cassette = if recording { Cassette::default() } else { Cassette::load(&path)? };
+        Ok(VcrTransport {
+            used: Mutex::new(vec![false; cassette.interactions.len()]),
+            cassette: Arc::new(Mutex::new(cassette)),
+            save_lock: Arc::new(tokio::sync::Mutex::new(())),
+            path,
+            recording,
+            inner,
+            redactor: Redactor::new(),
+            replay_timing: false,
+        })
+    }
+
+    /// Sets the redactor applied to recorded interactions and to requests being matched.
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
+    /// Replays chunks with their recorded delays instead of all at once.
+    pub fn with_replay_timing(mut self, replay_timing: bool) -> Self {
+        self.replay_timing = replay_timing;
+        self
+    }
+
+    /// Whether requests are being recorded rather than replayed.
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// A copy of the cassette as recorded (or loaded) so far.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+
+    fn cassette_request(&self, request: &Request) -> CassetteRequest {
+        let url = request.url();
+        let path = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        };
+        CassetteRequest {
+            method: request.method().to_string(),
+            path,
+            headers: self.redactor.headers(request.headers()),
+            body: self.redactor.body(request.body().and_then(|body| body.as_bytes()).unwrap_or_default()),
+        }
+    }
+
+    async fn record(&self, request: Request) -> Result<Response> {
+        let recorded_request = self.cassette_request(&request);
+        let response = self.inner.send(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let is_stream = headers
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/event-stream"));
+
+        let recording = Recording {
+            request: recorded_request,
+            status,
+            headers: self.redactor.headers(&headers),
+            is_stream,
+            chunks: Vec::new(),
+            buffer: Vec::new(),
+            last: Instant::now(),
+            redactor: self.redactor.clone(),
+            cassette: self.cassette.clone(),
+            save_lock: self.save_lock.clone(),
+            path: self.path.clone(),
+        };
+        // Chunks reach the caller as they arrive; the interaction is written once the body
+        // has been read to the end.
+        let body = futures_util::stream::unfold(Some((response.bytes_stream(), recording)), |state| async move {
+            let (mut body, mut recording) = state?;
+            match body.next().await {
+                Some(Ok(bytes)) => {
+                    recording.push(&bytes);
+                    Some((Ok(bytes), Some((body, recording))))
+                }
+                Some(Err(err)) => Some((Err(io::Error::other(err)), None)),
+                None => recording.finish().await.err().map(|err| (Err(err), None)),
+            }
+        });
+        let mut response = http::Response::new(reqwest::Body::wrap_stream(body));
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        Ok(Response::from(response))
+    }
+
+    fn find(&self, request: &Request) -> Result<Response> {
+        let wanted = self.cassette_request(request);
+        let cassette = self.cassette.lock().unwrap();
+        let mut used = self.used.lock().unwrap();
+        let index = cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .position(|(i, interaction)| {
+                !used[i]
+                    && interaction.request.method == wanted.method
+                    && interaction.request.path == wanted.path
+                    && interaction.request.body == wanted.body
+            })
+            .ok_or_else(|| {
+                Error::UnexpectedResponse(format!(
+                    "No unused interaction in cassette {} matches {} {}",
+                    self.path.display(),
+                    wanted.method,
+                    wanted.path
+                ))
+            })?;
+        used[index] = true;
+
+        let recorded = &cassette.interactions[index].response;
+        let status = StatusCode::from_u16(recorded.status)
+            .map_err(|err| Error::UnexpectedResponse(format!("Invalid status in cassette: {err}")))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &recorded.headers {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(name.as_str()), HeaderValue::from_str(value)) {
+                headers.insert(name, value);
+            }
+        }
+        Ok(replay(status, headers, recorded.chunks.clone(), self.replay_timing))
+    }
+}
+
+impl HttpTransport for VcrTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        if self.recording {
+            Box::pin(self.record(request))
+        } else {
+            let response = self.find(&request);
+            Box::pin(async move { response })
+        }
+    }
+}
+
+/// A response being recorded as its body passes through to the caller.
+struct Recording {
+    request: CassetteRequest,
+    status: StatusCode,
+    headers: BTreeMap<String, String>,
+    is_stream: bool,
+    chunks: Vec<CassetteChunk>,
+    buffer: Vec<u8>,
+    last: Instant,
+    redactor: Redactor,
+    cassette: Arc<Mutex<Cassette>>,
+    save_lock: Arc<tokio::sync::Mutex<()>>,
+    path: PathBuf,
+}
+
+impl Recording {
+    /// Adds body bytes, splitting streams into one chunk per server-sent event.
+    fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+        while self.is_stream && let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let event: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            self.chunks.push(chunk(&mut self.last, &event));
+        }
+    }
+
+    /// Adds the interaction to the cassette and writes the cassette to disk.
+    async fn finish(mut self) -> io::Result<()> {
+        if !self.buffer.is_empty() {
+            let rest = std::mem::take(&mut self.buffer);
+            self.chunks.push(chunk(&mut self.last, &rest));
+        }
+        let chunks = self
+            .chunks
+            .into_iter()
+            .map(|c| CassetteChunk { data: self.redactor.chunk(&c.data), ..c })
+            .collect();
+        let response = CassetteResponse { status: self.status.as_u16(), headers: self.headers, chunks };
+        self.cassette.lock().unwrap().interactions.push(Interaction { request: self.request, response });
+
+        // Writes are serialized, and each writes the latest cassette, so a slower write
+        // never replaces a newer one.
+        let _guard = self.save_lock.lock().await;
+        let cassette = self.cassette.lock().unwrap().clone();
+        let path = self.path;
+        tokio::task::spawn_blocking(move || {
+            cassette.save(&path).map_err(|err| io::Error::other(format!("Failed to write cassette {}: {err}", path.display())))
+        })
+        .await
+        .map_err(io::Error::other)?
+    }
+}
+
+fn chunk(last: &mut Instant, data: &[u8]) -> CassetteChunk {
+    let delay_ms = last.elapsed().as_millis() as u64;
+    *last = Instant::now();
+    CassetteChunk { delay_ms, data: String::from_utf8_lossy(data).into_owned() }
+}
+
+/// Builds a response whose body yields `chunks`, optionally with their recorded delays.
+fn replay(status: StatusCode, mut headers: HeaderMap, chunks: Vec<CassetteChunk>, timing: bool) -> Response {
+    // The body is re-chunked, so any recorded framing no longer applies.
+    headers.remove(reqwest::header::CONTENT_LENGTH);
+    headers.remove(reqwest::header::TRANSFER_ENCODING);
+    let stream = futures_util::stream::iter(chunks).then(move |chunk| async move {
+        if timing && chunk.delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(chunk.delay_ms)).await;
+        }
+        Ok::<_, io::Error>(Bytes::from(chunk.data))
+    });
+    let mut response = http::Response::new(reqwest::Body::wrap_stream(stream));
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
@@ -0,0 +1,152 @@
+// tests/audio_test.rs
+
+mod common;
+
+use common::{client, json_response};
+use futures_util::TryStreamExt;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{Body, StatusCode};
+use sh_openai_responses::client::audio::{create_speech, create_transcription, create_transcription_stream};
+use sh_openai_responses::interceptor::canned_response;
+use sh_openai_responses::models::audio::{
+    AudioResponseFormat, SpeechCreateParams, SpeechResponseFormat, SpeechVoice, Transcription,
+    TranscriptionCreateParams, TranscriptionStreamEvent,
+};
+use sh_openai_responses::transport::{InMemoryTransport, RecordedRequest};
+use std::sync::Arc;
+
+/// The form fields of a multipart request, as `(name, value)` pairs.
+fn form_fields(request: &RecordedRequest) -> Vec<(String, String)> {
+    let content_type = request.headers[CONTENT_TYPE].to_str().unwrap();
+    let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap();
+    let body = String::from_utf8(request.body.to_vec()).unwrap();
+    body.split(&format!("--{boundary}"))
+        .filter_map(|part| {
+            let (headers, value) = part.trim_start_matches("\r\n").split_once("\r\n\r\n")?;
+            let name = headers.split("name=\"").nth(1)?.split('"').next()?;
+            Some((name.to_string(), value.trim_end_matches("\r\n").to_string()))
+        })
+        .collect()
+}
+
+fn transcription_params() -> TranscriptionCreateParams {
+    let mut params = TranscriptionCreateParams::new(b"RIFF....WAVE".to_vec(), "note.wav", "gpt-4o-transcribe");
+    params.language = Some("en".to_string());
+    params
+}
+
+#[test]
+fn test_deserializes_verbose_transcription_and_stream_events() {
+    let transcription: Transcription = serde_json::from_value(serde_json::json!({
+        "task": "transcribe",
+        "language": "english",
+        "duration": 2.4,
+        "text": "Blood pressure one twenty over eighty.",
+        "words": [
+            { "word": "Blood", "start": 0.0, "end": 0.32 },
+            { "word": "pressure", "start": 0.32, "end": 0.8 }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(transcription.language.as_deref(), Some("english"));
+    assert_eq!(transcription.words.unwrap()[1].word, "pressure");
+    assert!(transcription.segments.is_none());
+
+    let delta: TranscriptionStreamEvent =
+        serde_json::from_str(r#"{"type":"transcript.text.delta","delta":"Blood"}"#).unwrap();
+    assert_eq!(delta, TranscriptionStreamEvent::TextDelta { delta: "Blood".to_string(), logprobs: None });
+    let done: TranscriptionStreamEvent =
+        serde_json::from_str(r#"{"type":"transcript.text.done","text":"Blood pressure."}"#).unwrap();
+    assert!(matches!(done, TranscriptionStreamEvent::TextDone { text, .. } if text == "Blood pressure."));
+    let unknown: TranscriptionStreamEvent =
+        serde_json::from_str(r#"{"type":"transcript.text.segment","text":"Blood"}"#).unwrap();
+    assert_eq!(unknown, TranscriptionStreamEvent::Unknown);
+}
+
+#[test]
+fn test_serializes_speech_params() {
+    let mut params = SpeechCreateParams::new("gpt-4o-mini-tts", "Take one tablet daily.", SpeechVoice::Coral);
+    params.response_format = Some(SpeechResponseFormat::Wav);
+    params.instructions = Some("Speak calmly.".to_string());
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "input": "Take one tablet daily.",
+            "model": "gpt-4o-mini-tts",
+            "voice": "coral",
+            "instructions": "Speak calmly.",
+            "response_format": "wav"
+        })
+    );
+
+    let voice: SpeechVoice = serde_json::from_str(r#""marin""#).unwrap();
+    assert_eq!(voice, SpeechVoice::Other("marin".to_string()));
+}
+
+#[tokio::test]
+async fn test_transcription_sends_multipart_form() {
+    let transport = Arc::new(InMemoryTransport::new(|_| {
+        json_response(&serde_json::json!({ "text": "Blood pressure one twenty over eighty." }))
+    }));
+    let client = client(transport.clone());
+
+    let mut params = transcription_params();
+    params.response_format = Some(AudioResponseFormat::Json);
+    let transcription = create_transcription(&client, params).await.unwrap();
+    assert_eq!(transcription.text, "Blood pressure one twenty over eighty.");
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.url.path(), "/v1/audio/transcriptions");
+    let fields = form_fields(request);
+    let field = |name: &str| fields.iter().find(|(field, _)| field == name).map(|(_, value)| value.as_str());
+    assert_eq!(field("model"), Some("gpt-4o-transcribe"));
+    assert_eq!(field("file"), Some("RIFF....WAVE"));
+    assert_eq!(field("language"), Some("en"));
+    assert_eq!(field("response_format"), Some("json"));
+    assert_eq!(field("stream"), None);
+}
+
+#[tokio::test]
+async fn test_transcription_stream_skips_unknown_events() {
+    let transport = Arc::new(InMemoryTransport::new(|_| {
+        let body = [
+            r#"{"type":"transcript.text.delta","delta":"Blood"}"#,
+            r#"{"type":"transcript.text.segment","text":"Blood"}"#,
+            r#"{"type":"transcript.text.done","text":"Blood pressure."}"#,
+        ]
+        .map(|event| format!("data: {event}\n\n"))
+        .concat();
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+        canned_response(StatusCode::OK, headers, body)
+    }));
+    let client = client(transport.clone());
+
+    let events: Vec<_> = create_transcription_stream(&client, transcription_params()).await.unwrap().try_collect().await.unwrap();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0], TranscriptionStreamEvent::TextDelta { delta: "Blood".to_string(), logprobs: None });
+    assert_eq!(events[1], TranscriptionStreamEvent::Unknown);
+    assert!(matches!(&events[2], TranscriptionStreamEvent::TextDone { text, .. } if text == "Blood pressure."));
+
+    let fields = form_fields(&transport.requests()[0]);
+    assert!(fields.contains(&("stream".to_string(), "true".to_string())));
+}
+
+#[tokio::test]
+async fn test_speech_streams_audio_bytes() {
+    let transport = Arc::new(InMemoryTransport::new(|_| {
+        let chunks = ["ID3", "audio", "frames"].map(|chunk| Ok::<_, std::io::Error>(chunk.as_bytes().to_vec()));
+        canned_response(StatusCode::OK, HeaderMap::new(), Body::wrap_stream(futures_util::stream::iter(chunks)))
+    }));
+    let client = client(transport.clone());
+
+    let params = SpeechCreateParams::new("gpt-4o-mini-tts", "Take one tablet daily.", SpeechVoice::Coral);
+    let chunks: Vec<_> = create_speech(&client, params).await.unwrap().try_collect().await.unwrap();
+    assert_eq!(chunks.concat(), b"ID3audioframes");
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.url.path(), "/v1/audio/speech");
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["voice"], "coral");
+    assert_eq!(body["input"], "Take one tablet daily.");
+}
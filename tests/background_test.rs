@@ -0,0 +1,80 @@
+// tests/background_test.rs
+
+mod common;
+
+use common::{client, json_response, response_json};
+use reqwest::Method;
+use sh_openai_responses::client::background::{wait_for_response, PollOptions, PollOutcome};
+use sh_openai_responses::models::responses::ResponseStatus;
+use sh_openai_responses::transport::InMemoryTransport;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+fn fast() -> PollOptions {
+    PollOptions::default().with_interval(Duration::from_millis(1), Duration::from_millis(5))
+}
+
+#[tokio::test]
+async fn test_polls_until_finished_or_timed_out() {
+    let polls = Arc::new(AtomicUsize::new(0));
+    let counter = polls.clone();
+    let transport = Arc::new(InMemoryTransport::new(move |_| {
+        let status = match counter.fetch_add(1, Ordering::SeqCst) {
+            0 => "queued",
+            1 => "in_progress",
+            _ => "completed",
+        };
+        json_response(&response_json(status))
+    }));
+    let outcome = wait_for_response(&client(transport.clone()), "resp_1", fast()).await.unwrap();
+    assert!(matches!(&outcome, PollOutcome::Finished(response) if response.status == Some(ResponseStatus::Completed)));
+    assert_eq!(transport.requests().len(), 3);
+    assert!(transport.requests().iter().all(|r| r.method == Method::GET && r.url.path() == "/v1/responses/resp_1"));
+
+    let pending = Arc::new(InMemoryTransport::new(|_| {
+        json_response(&response_json("in_progress"))
+    }));
+    let options = fast().with_timeout(Duration::from_millis(20));
+    let outcome = wait_for_response(&client(pending), "resp_1", options).await.unwrap();
+    assert!(matches!(outcome, PollOutcome::TimedOut(_)));
+    assert_eq!(outcome.response().status, Some(ResponseStatus::InProgress));
+}
+
+#[tokio::test]
+async fn test_cancellation_cancels_the_response() {
+    let token = CancellationToken::new();
+    let trigger = token.clone();
+    let transport = Arc::new(InMemoryTransport::new(move |request| {
+        if request.method == Method::POST {
+            return json_response(&response_json("cancelled"));
+        }
+        trigger.cancel();
+        json_response(&response_json("in_progress"))
+    }));
+    let client = client(transport.clone());
+
+    let options = PollOptions::default().with_cancellation(token);
+    let outcome = wait_for_response(&client, "resp_1", options).await.unwrap();
+    assert_eq!(outcome.into_response().status, Some(ResponseStatus::Cancelled));
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!((requests[1].method.clone(), requests[1].url.path()), (Method::POST, "/v1/responses/resp_1/cancel"));
+}
+
+#[tokio::test]
+async fn test_invalid_multipliers_do_not_panic() {
+    for multiplier in [f64::NAN, -2.0, f64::INFINITY, 1e300] {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        let transport = Arc::new(InMemoryTransport::new(move |_| {
+            let status = if counter.fetch_add(1, Ordering::SeqCst) < 2 { "in_progress" } else { "completed" };
+            json_response(&response_json(status))
+        }));
+        let options = PollOptions { multiplier, ..fast() };
+        let outcome = wait_for_response(&client(transport), "resp_1", options).await.unwrap();
+        assert!(matches!(outcome, PollOutcome::Finished(_)));
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+    }
+}
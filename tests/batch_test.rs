@@ -0,0 +1,106 @@
+// tests/batch_test.rs
+
+use sh_openai_responses::client::batches::{batch_requests, parse_batch_output, to_jsonl};
+use sh_openai_responses::models::batches::BatchResult;
+use sh_openai_responses::models::responses::{
+    ChatCompletion, ChatCompletionCreateParams, ChatMessage, Response, ResponseCreateParams,
+    ResponseInputParam,
+};
+
+#[test]
+fn test_writes_batch_input_lines() {
+    let params = vec![
+        ResponseCreateParams {
+            model: Some("gpt-4.1".to_string()),
+            input: Some(ResponseInputParam::String("Summarize visit 1".to_string())),
+            ..Default::default()
+        },
+        ResponseCreateParams {
+            model: Some("gpt-4.1".to_string()),
+            input: Some(ResponseInputParam::String("Summarize visit 2".to_string())),
+            ..Default::default()
+        },
+    ];
+
+    let jsonl = to_jsonl(&batch_requests(params)).unwrap();
+    let lines: Vec<serde_json::Value> =
+        jsonl.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["custom_id"], "request-0");
+    assert_eq!(lines[0]["method"], "POST");
+    assert_eq!(lines[0]["url"], "/v1/responses");
+    assert_eq!(lines[0]["body"]["input"], "Summarize visit 1");
+    assert_eq!(lines[1]["custom_id"], "request-1");
+
+    let chat = vec![ChatCompletionCreateParams {
+        model: "gpt-4o-mini".to_string(),
+        messages: vec![ChatMessage { role: "user".to_string(), content: "Hi".to_string() }],
+        ..Default::default()
+    }];
+    let jsonl = to_jsonl(&batch_requests(chat)).unwrap();
+    assert!(jsonl.contains(r#""url":"/v1/chat/completions""#));
+}
+
+#[test]
+fn test_reads_batch_output_lines() {
+    let output = [
+        serde_json::json!({
+            "id": "batch_req_1",
+            "custom_id": "request-0",
+            "response": {
+                "status_code": 200,
+                "request_id": "req_1",
+                "body": {
+                    "id": "resp_1",
+                    "object": "response",
+                    "created_at": 1741476542,
+                    "model": "gpt-4.1",
+                    "output": [],
+                    "parallel_tool_calls": true,
+                    "tool_choice": "auto",
+                    "tools": []
+                }
+            },
+            "error": null
+        }),
+        serde_json::json!({
+            "id": "batch_req_2",
+            "custom_id": "request-1",
+            "response": {
+                "status_code": 400,
+                "request_id": "req_2",
+                "body": { "error": { "message": "Invalid model", "type": "invalid_request_error", "code": "model_not_found" } }
+            },
+            "error": null
+        }),
+        serde_json::json!({
+            "id": "batch_req_3",
+            "custom_id": "request-2",
+            "response": null,
+            "error": { "code": "batch_expired", "message": "This request could not be executed before the completion window expired." }
+        }),
+    ]
+    .iter()
+    .map(|line| line.to_string())
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    let results: Vec<BatchResult<Response>> = parse_batch_output(&output).unwrap();
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0].custom_id, "request-0");
+    assert_eq!(results[0].result.as_ref().unwrap().id, "resp_1");
+
+    let error = results[1].result.as_ref().unwrap_err();
+    assert_eq!(error.status_code, Some(400));
+    assert_eq!(error.code.as_deref(), Some("model_not_found"));
+    assert_eq!(error.message, "Invalid model");
+
+    let error = results[2].result.as_ref().unwrap_err();
+    assert_eq!(error.status_code, None);
+    assert_eq!(error.code.as_deref(), Some("batch_expired"));
+
+    // A body that does not match the requested type is an error for the whole file.
+    assert!(parse_batch_output::<ChatCompletion>(&output).is_err());
+}
@@ -0,0 +1,148 @@
+// tests/budget_test.rs
+
+mod common;
+
+use common::{answering, client, offline, response_json, usage_json};
+use futures_util::future::BoxFuture;
+use serde_json::json;
+use sh_openai_responses::budget::{
+    Budget, BudgetLimitKind, BudgetLimits, BudgetStore, BudgetUsage, BudgetViolation, BudgetWindows,
+    InMemoryBudgetStore,
+};
+use sh_openai_responses::client::{create_chat_completion, create_response, Error, Result};
+use sh_openai_responses::models::responses::{
+    ChatCompletionCreateParams, ChatMessage, ResponseCreateParams, ResponseInputParam, Response,
+};
+use std::sync::{Arc, Mutex};
+
+fn completed_response() -> Response {
+    let mut response = response_json("completed");
+    response["usage"] = usage_json(600, 0, 400, 0);
+    serde_json::from_value(response).unwrap()
+}
+
+#[tokio::test]
+async fn test_budget_refuses_before_network() {
+    let budget = Budget::new(BudgetLimits::new().requests_per_minute(0));
+    let client = client(offline()).with_budget(Arc::new(budget));
+
+    let params = ResponseCreateParams {
+        model: Some("gpt-4.1".to_string()),
+        input: Some(ResponseInputParam::String("Hello".to_string())),
+        ..Default::default()
+    };
+    match create_response(&client, params).await {
+        Err(Error::BudgetExceeded { key, kind, .. }) => {
+            assert_eq!(key, "default");
+            assert_eq!(kind, BudgetLimitKind::RequestsPerMinute);
+        }
+        other => panic!("expected BudgetExceeded, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_budget_tracks_tokens_per_key() {
+    let budget = Budget::new(BudgetLimits::new().tokens_per_day(1_500))
+        .with_key_limits("triage", BudgetLimits::new().tokens_per_day(10_000))
+        .with_key_metadata("team");
+
+    let metadata = [("team".to_string(), "triage".to_string())].into_iter().collect();
+    assert_eq!(budget.key_for(Some(&metadata)), "triage");
+    assert_eq!(budget.key_for(None), "default");
+
+    let response = completed_response();
+    for key in ["default", "triage"] {
+        budget.acquire(key).await.unwrap();
+        budget.record(key, &response).await.unwrap();
+        budget.acquire(key).await.unwrap();
+        budget.record(key, &response).await.unwrap();
+    }
+
+    let usage = budget.usage("default").await.unwrap();
+    assert_eq!(usage.tokens_today, 2_000);
+    assert_eq!(usage.requests_this_minute, 2);
+    assert!(usage.dollars_today > 0.0);
+
+    assert!(matches!(
+        budget.acquire("default").await,
+        Err(Error::BudgetExceeded { kind: BudgetLimitKind::TokensPerDay, .. })
+    ));
+    budget.acquire("triage").await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_acquires_do_not_overspend() {
+    let budget = Arc::new(Budget::new(BudgetLimits::new().requests_per_minute(10)));
+
+    let tasks: Vec<_> = (0..50)
+        .map(|_| {
+            let budget = budget.clone();
+            tokio::spawn(async move { budget.acquire("default").await.is_ok() })
+        })
+        .collect();
+    let mut allowed = 0;
+    for task in tasks {
+        allowed += task.await.unwrap() as usize;
+    }
+    assert_eq!(allowed, 10);
+    assert_eq!(budget.usage("default").await.unwrap().requests_this_minute, 10);
+}
+
+/// Counts requests in memory but fails to record usage.
+#[derive(Debug, Default)]
+struct FailingUsageStore(InMemoryBudgetStore);
+
+impl BudgetStore for FailingUsageStore {
+    fn usage<'a>(&'a self, key: &'a str, windows: BudgetWindows) -> BoxFuture<'a, Result<BudgetUsage>> {
+        self.0.usage(key, windows)
+    }
+
+    fn try_record_request<'a>(
+        &'a self,
+        key: &'a str,
+        windows: BudgetWindows,
+        limits: &'a BudgetLimits,
+    ) -> BoxFuture<'a, Result<Option<BudgetViolation>>> {
+        self.0.try_record_request(key, windows, limits)
+    }
+
+    fn record_usage<'a>(&'a self, _: &'a str, _: BudgetWindows, _: u64, _: f64) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async { Err(Error::UnexpectedResponse("store unavailable".to_string())) })
+    }
+}
+
+#[tokio::test]
+async fn test_chat_completions_use_the_metadata_key_and_report_record_errors() {
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let store = Arc::new(FailingUsageStore::default());
+    let budget = Budget::new(BudgetLimits::new())
+        .with_key_metadata("team")
+        .with_store(store.clone())
+        .with_record_error_handler({
+            let failures = failures.clone();
+            move |key, error| failures.lock().unwrap().push(format!("{key}: {error}"))
+        });
+    let completion = json!({
+        "id": "chatcmpl_1",
+        "object": "chat.completion",
+        "created": 1741476542,
+        "model": "gpt-4o-mini",
+        "choices": [],
+        "usage": { "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 }
+    });
+    let client = client(answering(completion)).with_budget(Arc::new(budget));
+
+    let params = ChatCompletionCreateParams {
+        model: "gpt-4o-mini".to_string(),
+        messages: vec![ChatMessage { role: "user".to_string(), content: "Hi".to_string() }],
+        metadata: Some([("team".to_string(), "triage".to_string())].into_iter().collect()),
+        ..Default::default()
+    };
+    let completion = create_chat_completion(&client, params).await.unwrap();
+    assert_eq!(completion.id, "chatcmpl_1");
+
+    assert_eq!(store.usage("triage", BudgetWindows::now()).await.unwrap().requests_this_minute, 1);
+    let failures = failures.lock().unwrap();
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].starts_with("triage: "), "{failures:?}");
+}
@@ -0,0 +1,120 @@
+// tests/builder_test.rs
+
+use sh_openai_responses::models::responses::{
+    EasyInputMessage, FunctionTool, ImageDetail, ResponseCodeInterpreterToolCallOutput,
+    ResponseComputerToolCallAction, ResponseContent, ResponseCreateParams, ResponseInputContent,
+    ResponseInputItem, ResponseOutputItem, Tool,
+};
+use std::collections::HashMap;
+
+#[test]
+fn test_builder_serializes_request() {
+    let params = ResponseCreateParams::builder("gpt-4.1")
+        .instructions("Answer as a triage nurse.")
+        .user_text("What does this rash look like?")
+        .user_image_url("https://example.com/rash.png")
+        .user(vec![
+            ResponseInputContent::image_file("file-1").with_detail(ImageDetail::High),
+            ResponseInputContent::file_data("history.pdf", "data:application/pdf;base64,JVBERi0="),
+        ])
+        .tool(Tool::Function(FunctionTool {
+            name: "lookup_patient".to_string(),
+            parameters: Some(HashMap::from([("type".to_string(), serde_json::json!("object"))])),
+            strict: Some(true),
+            type_field: "function".to_string(),
+            description: None,
+        }))
+        .temperature(0.2)
+        .metadata("ward", "b3")
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "model": "gpt-4.1",
+            "instructions": "Answer as a triage nurse.",
+            "input": [
+                { "role": "user", "content": "What does this rash look like?" },
+                {
+                    "role": "user",
+                    "content": [{ "type": "input_image", "detail": "auto", "image_url": "https://example.com/rash.png" }]
+                },
+                {
+                    "role": "user",
+                    "content": [
+                        { "type": "input_image", "detail": "high", "file_id": "file-1" },
+                        {
+                            "type": "input_file",
+                            "filename": "history.pdf",
+                            "file_data": "data:application/pdf;base64,JVBERi0="
+                        }
+                    ]
+                }
+            ],
+            "tools": [{
+                "type": "function",
+                "name": "lookup_patient",
+                "parameters": { "type": "object" },
+                "strict": true
+            }],
+            "temperature": 0.2,
+            "metadata": { "ward": "b3" }
+        })
+    );
+}
+
+#[test]
+fn test_tagged_content_round_trips() {
+    let item: ResponseInputItem = EasyInputMessage::user(vec![
+        ResponseInputContent::text("See attached."),
+        ResponseInputContent::file_url("https://example.com/labs.pdf"),
+    ])
+    .into();
+    let value = serde_json::to_value(&item).unwrap();
+    assert_eq!(value["content"][0], serde_json::json!({ "type": "input_text", "text": "See attached." }));
+    assert_eq!(serde_json::from_value::<ResponseInputItem>(value).unwrap(), item);
+
+    let refusal: ResponseContent =
+        serde_json::from_value(serde_json::json!({ "type": "refusal", "refusal": "I can't help with that." })).unwrap();
+    assert!(matches!(refusal, ResponseContent::OutputRefusal(_)));
+    assert_eq!(serde_json::to_value(&refusal).unwrap()["type"], "refusal");
+}
+
+#[test]
+fn test_tool_call_actions_and_outputs_round_trip() {
+    // The action and output structs carry their own `type` field, which serde's internal
+    // tagging would strip before the struct sees it.
+    let computer_call = serde_json::json!({
+        "type": "computer_call",
+        "id": "cu_1",
+        "call_id": "call_1",
+        "action": { "type": "click", "button": "left", "x": 10.0, "y": 20.0 },
+        "pending_safety_checks": [],
+        "status": "completed"
+    });
+    let item: ResponseOutputItem = serde_json::from_value(computer_call.clone()).unwrap();
+    let ResponseOutputItem::ComputerCall(call) = &item else { panic!("expected a computer call, got {item:?}") };
+    assert!(matches!(&call.action, ResponseComputerToolCallAction::Click(click) if click.button == "left"));
+    assert_eq!(serde_json::to_value(&item).unwrap(), computer_call);
+
+    let code_interpreter_call = serde_json::json!({
+        "type": "code_interpreter_call",
+        "id": "ci_1",
+        "code": "print(1)",
+        "container_id": "cntr_1",
+        "outputs": [
+            { "type": "logs", "logs": "1" },
+            { "type": "image", "url": "https://example.com/plot.png" }
+        ],
+        "status": "completed"
+    });
+    let item: ResponseOutputItem = serde_json::from_value(code_interpreter_call.clone()).unwrap();
+    let ResponseOutputItem::CodeInterpreterCall(call) = &item else { panic!("expected a code interpreter call, got {item:?}") };
+    let outputs = call.outputs.as_deref().unwrap();
+    assert!(matches!(&outputs[0], ResponseCodeInterpreterToolCallOutput::Logs(logs) if logs.logs == "1"));
+    assert!(matches!(&outputs[1], ResponseCodeInterpreterToolCallOutput::Image(_)));
+    assert_eq!(serde_json::to_value(&item).unwrap(), code_interpreter_call);
+
+    let unknown = serde_json::json!({ "type": "zoom", "factor": 2 });
+    assert!(serde_json::from_value::<ResponseComputerToolCallAction>(unknown).is_err());
+}
@@ -0,0 +1,145 @@
+// tests/cache_test.rs
+
+mod common;
+
+use common::{answering, message_response_json};
+use futures_util::future::BoxFuture;
+use futures_util::StreamExt;
+use sh_openai_responses::cache::{CacheBackend, CacheEntry, DiskCache, ResponseCache};
+use sh_openai_responses::client::raw::with_raw_response;
+use sh_openai_responses::client::{create_response, create_response_stream, Client, Error, Result};
+use sh_openai_responses::models::responses::{ResponseCreateParams, ResponseStreamEvent};
+use sh_openai_responses::transport::InMemoryTransport;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn upstream() -> Arc<InMemoryTransport> {
+    answering(message_response_json("Rest and fluids."))
+}
+
+fn client(transport: Arc<InMemoryTransport>, cache: ResponseCache) -> Client {
+    common::client(transport).with_cache(Arc::new(cache))
+}
+
+fn params(user: &str) -> ResponseCreateParams {
+    let mut params = ResponseCreateParams::builder("gpt-4.1").user_text("Fever advice?").build();
+    params.user = Some(user.to_string());
+    params
+}
+
+#[tokio::test]
+async fn test_repeated_requests_are_served_from_cache() {
+    let transport = upstream();
+    let client = client(transport.clone(), ResponseCache::in_memory(16).ignore_field("user"));
+
+    let first = create_response(&client, params("eval-1")).await.unwrap();
+    let second = create_response(&client, params("eval-2")).await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(transport.requests().len(), 1);
+
+    let events: Vec<_> = create_response_stream(&client, params("eval-3")).await.unwrap().collect().await;
+    assert_eq!(transport.requests().len(), 1);
+    let kinds: Vec<_> = events
+        .iter()
+        .map(|event| serde_json::to_value(event.as_ref().unwrap()).unwrap()["type"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            "response.created",
+            "response.output_item.added",
+            "response.output_text.delta",
+            "response.output_text.done",
+            "response.output_item.done",
+            "response.completed",
+        ]
+    );
+    assert!(matches!(&events[2], Ok(ResponseStreamEvent::ResponseTextDelta(delta)) if delta.delta == "Rest and fluids."));
+    assert!(matches!(&events[5], Ok(ResponseStreamEvent::ResponseCompleted(done)) if done.response == first));
+}
+
+#[tokio::test]
+async fn test_cache_hits_report_a_synthetic_raw_response() {
+    let transport = upstream();
+    let client = client(transport.clone(), ResponseCache::in_memory(16));
+
+    let first = with_raw_response(create_response(&client, params("eval-1"))).await.unwrap();
+    assert!(first.raw.headers.get("x-cache").is_none());
+
+    let second = with_raw_response(create_response(&client, params("eval-1"))).await.unwrap();
+    assert_eq!(transport.requests().len(), 1);
+    assert_eq!(second.data, first.data);
+    assert_eq!(second.raw.status, 200);
+    assert_eq!(second.raw.headers["x-cache"], "hit");
+    let body: serde_json::Value = serde_json::from_slice(&second.raw.body).unwrap();
+    assert_eq!(body["id"], "resp_1");
+}
+
+#[tokio::test]
+async fn test_disk_cache_persists_and_expires() {
+    let dir = std::env::temp_dir().join(format!("sh-openai-cache-{}", std::process::id()));
+    let disk = Arc::new(DiskCache::new(&dir).unwrap());
+
+    let transport = upstream();
+    let expiring = client(transport.clone(), ResponseCache::new(disk.clone()).with_ttl(Duration::ZERO));
+    create_response(&expiring, params("eval-1")).await.unwrap();
+    create_response(&expiring, params("eval-1")).await.unwrap();
+    assert_eq!(transport.requests().len(), 2);
+
+    // `user` is part of the key unless ignored.
+    let transport = upstream();
+    let persistent = client(transport.clone(), ResponseCache::new(disk));
+    create_response(&persistent, params("eval-1")).await.unwrap();
+    create_response(&persistent, params("eval-2")).await.unwrap();
+    assert_eq!(transport.requests().len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_sub_second_ttl_keeps_fresh_entries() {
+    let transport = upstream();
+    let client = client(transport.clone(), ResponseCache::in_memory(16).with_ttl(Duration::from_millis(300)));
+
+    create_response(&client, params("eval-1")).await.unwrap();
+    create_response(&client, params("eval-1")).await.unwrap();
+    assert_eq!(transport.requests().len(), 1);
+
+    tokio::time::sleep(Duration::from_millis(350)).await;
+    create_response(&client, params("eval-1")).await.unwrap();
+    assert_eq!(transport.requests().len(), 2);
+}
+
+/// A backend whose reads and writes always fail.
+#[derive(Debug)]
+struct UnavailableCache;
+
+impl CacheBackend for UnavailableCache {
+    fn get<'a>(&'a self, _: &'a str) -> BoxFuture<'a, Result<Option<CacheEntry>>> {
+        Box::pin(async { Err(Error::UnexpectedResponse("cache unavailable".to_string())) })
+    }
+
+    fn put<'a>(&'a self, _: &'a str, _: CacheEntry) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async { Err(Error::UnexpectedResponse("cache unavailable".to_string())) })
+    }
+}
+
+#[tokio::test]
+async fn test_backend_failures_reach_the_error_handler() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let cache = ResponseCache::new(Arc::new(UnavailableCache)).with_error_handler({
+        let errors = errors.clone();
+        move |key, error| errors.lock().unwrap().push((key.to_string(), error.to_string()))
+    });
+    let transport = upstream();
+    let client = client(transport.clone(), cache);
+
+    create_response(&client, params("eval-1")).await.unwrap();
+    assert_eq!(transport.requests().len(), 1);
+
+    let errors = errors.lock().unwrap();
+    // One failed read before the request and one failed write after it.
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].0, errors[1].0);
+    assert!(errors.iter().all(|(_, error)| error.contains("cache unavailable")));
+}
@@ -0,0 +1,87 @@
+// tests/capabilities_test.rs
+
+mod common;
+
+use common::{client, offline};
+use sh_openai_responses::capabilities::{CapabilityRegistry, CapabilityViolation};
+use sh_openai_responses::client::{create_response, Error};
+use sh_openai_responses::models::responses::{
+    Reasoning, ReasoningEffort, ResponseCreateParams, ResponseInputParam,
+};
+use std::sync::Arc;
+
+fn params(model: &str) -> ResponseCreateParams {
+    ResponseCreateParams {
+        model: Some(model.to_string()),
+        input: Some(ResponseInputParam::String("Summarize the discharge note.".to_string())),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_validate_catches_incompatible_parameters() {
+    let registry = CapabilityRegistry::builtin();
+
+    let reasoning_on_chat_model = ResponseCreateParams {
+        reasoning: Some(Reasoning { effort: Some(ReasoningEffort::High), ..Default::default() }),
+        max_output_tokens: Some(64_000),
+        ..params("gpt-4.1-2025-04-14")
+    };
+    assert_eq!(
+        registry.validate(&reasoning_on_chat_model),
+        Err(vec![
+            CapabilityViolation::ReasoningUnsupported,
+            CapabilityViolation::MaxOutputTokensExceeded { requested: 64_000, max: 32_768 },
+        ])
+    );
+
+    let sampling_on_reasoning_model =
+        ResponseCreateParams { temperature: Some(0.2), top_p: Some(0.9), ..params("o3") };
+    assert_eq!(
+        registry.validate(&sampling_on_reasoning_model),
+        Err(vec![
+            CapabilityViolation::SamplingUnsupported { parameter: "temperature" },
+            CapabilityViolation::SamplingUnsupported { parameter: "top_p" },
+        ])
+    );
+
+    assert_eq!(registry.validate(&ResponseCreateParams { temperature: Some(0.2), ..params("gpt-4o") }), Ok(()));
+    assert_eq!(registry.validate(&ResponseCreateParams { temperature: Some(0.2), ..params("my-fine-tune") }), Ok(()));
+}
+
+#[test]
+fn test_gpt_5_chat_variants_accept_sampling() {
+    let registry = CapabilityRegistry::builtin();
+
+    let sampling = |model| ResponseCreateParams { temperature: Some(0.2), top_p: Some(0.9), ..params(model) };
+    assert_eq!(registry.validate(&sampling("gpt-5-chat-latest")), Ok(()));
+    assert!(!registry.get("gpt-5-chat-latest").unwrap().reasoning);
+    assert!(registry.get("gpt-5-2025-08-07").unwrap().reasoning);
+    assert!(registry.validate(&sampling("gpt-5-mini-2025-08-07")).is_err());
+}
+
+#[test]
+fn test_model_variants_do_not_inherit_their_family() {
+    let registry = CapabilityRegistry::builtin();
+
+    let o1_mini = registry.get("o1-mini-2024-09-12").unwrap();
+    assert!(!o1_mini.image_input);
+    assert!(!o1_mini.tools);
+    assert!(registry.get("o1").unwrap().image_input);
+    assert_eq!(registry.get("o3-pro"), None);
+    assert_eq!(registry.get("gpt-4o-realtime-preview"), None);
+}
+
+#[tokio::test]
+async fn test_client_refuses_incompatible_parameters_before_network() {
+    let client = client(offline()).with_capabilities(Arc::new(CapabilityRegistry::builtin()));
+
+    let request = ResponseCreateParams { temperature: Some(0.7), ..params("gpt-5-mini") };
+    match create_response(&client, request).await {
+        Err(Error::IncompatibleParameters { model, violations }) => {
+            assert_eq!(model, "gpt-5-mini");
+            assert_eq!(violations, vec![CapabilityViolation::SamplingUnsupported { parameter: "temperature" }]);
+        }
+        other => panic!("expected IncompatibleParameters, got {other:?}"),
+    }
+}
@@ -55,6 +55,13 @@ async fn test_create_standard_response() {
         top_p: None,
         truncation: None,
         user: None, // The client method will set this to false
+        conversation: None,
+        max_tool_calls: None,
+        prompt_cache_key: None,
+        safety_identifier: None,
+        stream_options: None,
+        top_logprobs: None,
+        extra: Default::default(),
     };
 
     // 2. Act
@@ -102,6 +109,13 @@ async fn test_create_streaming_response() {
         top_p: None,
         truncation: None,
         user: None, // The client method will set this to false
+        conversation: None,
+        max_tool_calls: None,
+        prompt_cache_key: None,
+        safety_identifier: None,
+        stream_options: None,
+        top_logprobs: None,
+        extra: Default::default(),
     };
 
 
@@ -211,6 +225,13 @@ async fn test_create_tools_response() {
         top_p: None,
         truncation: None,
         user: None,
+        conversation: None,
+        max_tool_calls: None,
+        prompt_cache_key: None,
+        safety_identifier: None,
+        stream_options: None,
+        top_logprobs: None,
+        extra: Default::default(),
     };
 
     // 2. Act
@@ -0,0 +1,86 @@
+// tests/common/mod.rs
+
+//! Fixtures shared by the integration tests. Each test crate uses only some of them.
+
+#![allow(dead_code)]
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use sh_openai_responses::client::Client;
+use sh_openai_responses::interceptor::canned_response;
+use sh_openai_responses::transport::{HttpTransport, InMemoryTransport};
+use std::sync::Arc;
+
+pub const BASE_URL: &str = "https://api.example.test/v1";
+
+/// A Responses API response `resp_1` with the given status and no output.
+pub fn response_json(status: &str) -> Value {
+    json!({
+        "id": "resp_1",
+        "object": "response",
+        "created_at": 1741476542,
+        "model": "gpt-4.1-2025-04-14",
+        "status": status,
+        "output": [],
+        "parallel_tool_calls": true,
+        "tool_choice": "auto",
+        "tools": []
+    })
+}
+
+/// A completed response whose only output is an assistant message saying `text`.
+pub fn message_response_json(text: &str) -> Value {
+    let mut response = response_json("completed");
+    response["output"] = json!([{
+        "type": "message",
+        "id": "msg_1",
+        "role": "assistant",
+        "status": "completed",
+        "content": [{ "type": "output_text", "text": text, "annotations": [] }]
+    }]);
+    response
+}
+
+/// A `usage` object with the given token counts.
+pub fn usage_json(input_tokens: u64, cached_tokens: u64, output_tokens: u64, reasoning_tokens: u64) -> Value {
+    json!({
+        "input_tokens": input_tokens,
+        "input_tokens_details": { "cached_tokens": cached_tokens },
+        "output_tokens": output_tokens,
+        "output_tokens_details": { "reasoning_tokens": reasoning_tokens },
+        "total_tokens": input_tokens + output_tokens
+    })
+}
+
+/// A `response.output_text.delta` event for message `msg_1`.
+pub fn text_delta_json(delta: &str, sequence_number: u64) -> Value {
+    json!({
+        "type": "response.output_text.delta",
+        "content_index": 0,
+        "delta": delta,
+        "item_id": "msg_1",
+        "output_index": 0,
+        "sequence_number": sequence_number
+    })
+}
+
+/// A `200 OK` response with `body` as JSON.
+pub fn json_response(body: &Value) -> reqwest::Response {
+    canned_response(StatusCode::OK, HeaderMap::new(), body.to_string())
+}
+
+/// A transport that answers every request with `body`.
+pub fn answering(body: Value) -> Arc<InMemoryTransport> {
+    Arc::new(InMemoryTransport::new(move |_| json_response(&body)))
+}
+
+/// A transport that fails the test if a request reaches it.
+pub fn offline() -> Arc<InMemoryTransport> {
+    Arc::new(InMemoryTransport::new(|request| panic!("unexpected request to {}", request.url)))
+}
+
+/// A client for [`BASE_URL`] that sends requests through `transport`.
+pub fn client(transport: Arc<dyn HttpTransport>) -> Client {
+    Client::new_with_base_url("sk-test".to_string(), BASE_URL.to_string()).with_transport(transport)
+}
@@ -0,0 +1,109 @@
+// tests/cost_test.rs
+
+mod common;
+
+use common::{response_json as base_response_json, usage_json};
+use sh_openai_responses::cost::{CostTracker, ModelPricing, PricingTable, UsageCost, UNTAGGED};
+use sh_openai_responses::models::responses::{ChatCompletion, Response, ServiceTier};
+
+fn response_json(model: &str, service_tier: &str) -> serde_json::Value {
+    let mut response = base_response_json("completed");
+    response["model"] = model.into();
+    response["service_tier"] = service_tier.into();
+    response["usage"] = usage_json(1_000_000, 400_000, 500_000, 100_000);
+    response
+}
+
+fn approx_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn test_response_cost_uses_snapshot_and_tier_pricing() {
+    let pricing = PricingTable::builtin();
+
+    let response: Response =
+        serde_json::from_value(response_json("gpt-4.1-2025-04-14", "default")).unwrap();
+    let cost = response.cost(&pricing).expect("gpt-4.1 should be priced");
+    // 600k uncached * $2 + 400k cached * $0.50 + 500k output * $8
+    assert!(approx_eq(cost.input, 1.2));
+    assert!(approx_eq(cost.cached_input, 0.2));
+    assert!(approx_eq(cost.output, 4.0));
+    assert!(approx_eq(cost.total(), 5.4));
+
+    let response: Response = serde_json::from_value(response_json("o3", "flex")).unwrap();
+    let cost = response.cost(&pricing).unwrap();
+    assert!(approx_eq(cost.total(), 0.6 + 0.1 + 2.0));
+}
+
+#[test]
+fn test_pricing_overrides_from_config() {
+    let overrides: PricingTable = serde_json::from_value(serde_json::json!({
+        "gpt-4.1": { "default": { "input": 1.0, "output": 2.0 } },
+        "in-house-model": { "default": { "input": 0.5, "output": 0.5 } }
+    }))
+    .unwrap();
+    let mut pricing = PricingTable::builtin();
+    pricing.merge(overrides);
+
+    assert_eq!(
+        pricing.get("gpt-4.1", Some(ServiceTier::Default)),
+        Some(&ModelPricing::new(1.0, None, 2.0))
+    );
+    // Priority pricing for gpt-4.1 is untouched by the override.
+    assert_eq!(pricing.get("gpt-4.1", Some(ServiceTier::Priority)).unwrap().input, 3.5);
+    // Tiers without their own entry fall back to default pricing.
+    assert!(pricing.get("in-house-model", Some(ServiceTier::Flex)).is_some());
+    assert!(pricing.get("unknown-model", None).is_none());
+}
+
+#[test]
+fn test_model_variants_are_not_priced_as_their_family() {
+    let pricing = PricingTable::builtin();
+    let o1 = pricing.get("o1", None).unwrap();
+
+    let o1_pro = pricing.get("o1-pro-2025-03-19", None).unwrap();
+    assert_eq!(o1_pro, &ModelPricing::new(150.0, None, 600.0));
+    let o1_mini = pricing.get("o1-mini", None).unwrap();
+    assert_ne!(o1_mini, o1);
+    assert_eq!(o1_mini.input, 1.1);
+
+    // Variants without their own entry are unpriced rather than priced as the base model.
+    assert!(pricing.get("o3-deep-research", None).is_none());
+    assert!(pricing.get("gpt-4o-audio-preview", None).is_none());
+    assert!(pricing.get("gpt-4o-2024-08", None).is_none());
+    assert_eq!(pricing.get("o3-2025-04-16", None), pricing.get("o3", None));
+}
+
+#[test]
+fn test_cost_tracker_aggregates_per_tag() {
+    let tracker = CostTracker::new(PricingTable::builtin()).with_tag_key("cost_center");
+
+    let metadata = [("cost_center".to_string(), "cardiology".to_string())].into_iter().collect();
+    let tag = tracker.tag_for(Some(&metadata));
+    assert_eq!(tag, "cardiology");
+
+    let response: Response = serde_json::from_value(response_json("gpt-4.1", "default")).unwrap();
+    tracker.record(&tag, &response);
+    tracker.record(&tag, &response);
+
+    let chat: ChatCompletion = serde_json::from_value(serde_json::json!({
+        "id": "chatcmpl_123",
+        "object": "chat.completion",
+        "created": 1741476542,
+        "model": "gpt-4o-mini",
+        "choices": [],
+        "usage": { "prompt_tokens": 1_000_000, "completion_tokens": 1_000_000, "total_tokens": 2_000_000 }
+    }))
+    .unwrap();
+    tracker.record(&tracker.tag_for(None), &chat);
+
+    let cardiology = tracker.spend_for("cardiology").unwrap();
+    assert_eq!(cardiology.requests, 2);
+    assert_eq!(cardiology.tokens.input_tokens, 2_000_000);
+    assert!(approx_eq(cardiology.cost.total(), 10.8));
+
+    let untagged = tracker.spend_for(UNTAGGED).unwrap();
+    assert!(approx_eq(untagged.cost.total(), 0.75));
+    assert!(approx_eq(tracker.total().total(), 11.55));
+}
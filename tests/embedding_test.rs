@@ -0,0 +1,94 @@
+// tests/embedding_test.rs
+
+mod common;
+
+use common::{client, json_response};
+use sh_openai_responses::client::embeddings::create_embedding_batched;
+use sh_openai_responses::models::embeddings::{
+    CreateEmbeddingResponse, Embedding, EmbeddingCreateParams, EmbeddingInput, EncodingFormat,
+};
+use sh_openai_responses::transport::InMemoryTransport;
+use std::sync::Arc;
+
+#[test]
+fn test_decodes_float_and_base64_embeddings() {
+    // [0.5, -1.0] as little-endian f32s: 00 00 00 3f, 00 00 80 bf.
+    let response: CreateEmbeddingResponse = serde_json::from_value(serde_json::json!({
+        "object": "list",
+        "data": [
+            { "object": "embedding", "index": 0, "embedding": [0.5, -1.0] },
+            { "object": "embedding", "index": 1, "embedding": "AAAAPwAAgL8=" }
+        ],
+        "model": "text-embedding-3-small",
+        "usage": { "prompt_tokens": 8, "total_tokens": 8 }
+    }))
+    .unwrap();
+
+    assert_eq!(response.data[0].embedding, vec![0.5, -1.0]);
+    assert_eq!(response.data[1].embedding, vec![0.5, -1.0]);
+
+    let truncated = serde_json::json!({ "object": "embedding", "index": 0, "embedding": "AAAA" });
+    assert!(serde_json::from_value::<Embedding>(truncated).is_err());
+}
+
+#[test]
+fn test_embedding_params_serialize_and_split_into_batches() {
+    let mut params = EmbeddingCreateParams::new("text-embedding-3-small", "chest pain on exertion");
+    params.dimensions = Some(256);
+    params.encoding_format = Some(EncodingFormat::Base64);
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "input": "chest pain on exertion",
+            "model": "text-embedding-3-small",
+            "dimensions": 256,
+            "encoding_format": "base64"
+        })
+    );
+
+    let inputs: Vec<String> = (0..5).map(|i| format!("note {i}")).collect();
+    let chunks = EmbeddingInput::from(inputs).into_chunks(2);
+    assert_eq!(chunks.iter().map(EmbeddingInput::len).collect::<Vec<_>>(), vec![2, 2, 1]);
+    assert_eq!(chunks[2], EmbeddingInput::StringArray(vec!["note 4".to_string()]));
+
+    let tokens = EmbeddingInput::Tokens(vec![1, 2, 3]).into_chunks(2);
+    assert_eq!(tokens, vec![EmbeddingInput::Tokens(vec![1, 2, 3])]);
+}
+
+#[tokio::test]
+async fn test_batched_embeddings_keep_global_indices_and_sum_usage() {
+    // Embeds "note {n}" as [n], listing each batch's embeddings in reverse order.
+    let transport = Arc::new(InMemoryTransport::new(|request| {
+        let params: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let inputs = params["input"].as_array().unwrap();
+        let data: Vec<_> = inputs
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, input)| {
+                let n: f32 = input.as_str().unwrap().trim_start_matches("note ").parse().unwrap();
+                serde_json::json!({ "object": "embedding", "index": index, "embedding": [n] })
+            })
+            .collect();
+        let tokens = 2 * inputs.len();
+        json_response(&serde_json::json!({
+            "object": "list",
+            "data": data,
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": tokens, "total_tokens": tokens }
+        }))
+    }));
+    let client = client(transport.clone());
+
+    let inputs: Vec<String> = (0..5).map(|i| format!("note {i}")).collect();
+    let params = EmbeddingCreateParams::new("text-embedding-3-small", inputs);
+    let response = create_embedding_batched(&client, params, 2).await.unwrap();
+
+    assert_eq!(transport.requests().len(), 3);
+    let indices: Vec<_> = response.data.iter().map(|embedding| embedding.index).collect();
+    assert_eq!(indices, [0, 1, 2, 3, 4]);
+    let embeddings: Vec<_> = response.data.iter().map(|embedding| embedding.embedding[0]).collect();
+    assert_eq!(embeddings, [0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(response.usage.prompt_tokens, 10);
+    assert_eq!(response.usage.total_tokens, 10);
+}
@@ -0,0 +1,84 @@
+// tests/extra_fields_test.rs
+
+use sh_openai_responses::models::responses::ResponseStreamEvent;
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn test_response_round_trips_unknown_fields() {
+    use sh_openai_responses::models::responses::{Response, ResponseOutputItem};
+
+    let json = serde_json::json!({
+        "id": "resp_1",
+        "object": "response",
+        "created_at": 1741476542,
+        "model": "gpt-4.1-2025-04-14",
+        "status": "completed",
+        "billing": { "payer": "developer" },
+        "output": [{
+            "type": "message",
+            "id": "msg_1",
+            "role": "assistant",
+            "status": "completed",
+            "phase": "final",
+            "content": [{ "type": "output_text", "text": "Rest and fluids.", "annotations": [], "logprobs": [] }]
+        }],
+        "parallel_tool_calls": true,
+        "tool_choice": "auto",
+        "tools": []
+    });
+
+    let response: Response = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(response.extra["billing"], serde_json::json!({ "payer": "developer" }));
+    let ResponseOutputItem::Message(message) = &response.output[0] else {
+        panic!("expected a message, got {:?}", response.output[0]);
+    };
+    assert_eq!(message.extra["phase"], "final");
+    assert!(!message.extra.contains_key("type"));
+
+    let reserialized = serde_json::to_value(&response).unwrap();
+    assert_eq!(reserialized["billing"], json["billing"]);
+    assert_eq!(reserialized["output"][0]["phase"], "final");
+    assert_eq!(reserialized["output"][0]["content"][0]["logprobs"], serde_json::json!([]));
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn test_stream_event_round_trips_unknown_fields() {
+    let json = serde_json::json!({
+        "type": "response.output_text.delta",
+        "content_index": 0,
+        "delta": "Rest",
+        "item_id": "msg_1",
+        "output_index": 0,
+        "sequence_number": 4,
+        "obfuscation": "x9Qa"
+    });
+
+    let event: ResponseStreamEvent = serde_json::from_value(json.clone()).unwrap();
+    let ResponseStreamEvent::ResponseTextDelta(delta) = &event else {
+        panic!("expected a text delta, got {event:?}");
+    };
+    assert_eq!(delta.extra.len(), 1);
+    assert_eq!(serde_json::to_value(&event).unwrap(), json);
+}
+
+#[cfg(not(feature = "extra-fields"))]
+#[test]
+fn test_unknown_fields_are_dropped_without_the_feature() {
+    let json = serde_json::json!({
+        "type": "response.output_text.delta",
+        "content_index": 0,
+        "delta": "Rest",
+        "item_id": "msg_1",
+        "output_index": 0,
+        "sequence_number": 4,
+        "obfuscation": "x9Qa"
+    });
+
+    let event: ResponseStreamEvent = serde_json::from_value(json).unwrap();
+    let ResponseStreamEvent::ResponseTextDelta(delta) = &event else {
+        panic!("expected a text delta, got {event:?}");
+    };
+    assert!(delta.extra.is_empty());
+    assert!(serde_json::to_value(&event).unwrap().get("obfuscation").is_none());
+}
@@ -0,0 +1,61 @@
+// tests/file_search_test.rs
+
+use sh_openai_responses::models::filters::attr;
+use sh_openai_responses::models::responses::{
+    FileSearchRanker, FileSearchTool, FileSearchToolRankingOptions, HybridSearchOptions, Tool,
+};
+
+#[test]
+fn test_file_search_tool_round_trips_captured_json() {
+    let captured = serde_json::json!({
+        "type": "file_search",
+        "vector_store_ids": ["vs_68a1c2"],
+        "filters": { "type": "eq", "key": "dept", "value": "cardiology" },
+        "max_num_results": 8,
+        "ranking_options": {
+            "ranker": "default-2024-11-15",
+            "score_threshold": 0.35,
+            "hybrid_search": { "embedding_weight": 0.7, "text_weight": 0.3 }
+        }
+    });
+
+    let tool: Tool = serde_json::from_value(captured.clone()).unwrap();
+    let Tool::FileSearch(file_search) = &tool else { panic!("expected a file search tool") };
+    let ranking = file_search.ranking_options.as_ref().unwrap();
+    assert_eq!(ranking.ranker, Some(FileSearchRanker::Default20241115));
+    assert_eq!(ranking.score_threshold, Some(0.35));
+    assert_eq!(
+        ranking.hybrid_search,
+        Some(HybridSearchOptions { embedding_weight: 0.7, text_weight: 0.3 })
+    );
+    assert_eq!(serde_json::to_value(&tool).unwrap(), captured);
+}
+
+#[test]
+fn test_file_search_ranking_options_serialize_only_set_fields() {
+    let tool = FileSearchTool {
+        type_field: "file_search".to_string(),
+        vector_store_ids: vec!["vs_68a1c2".to_string()],
+        filters: Some(attr("year").gte(2023)),
+        max_num_results: None,
+        ranking_options: Some(FileSearchToolRankingOptions {
+            ranker: Some(FileSearchRanker::Auto),
+            ..Default::default()
+        }),
+    };
+    assert_eq!(
+        serde_json::to_value(&tool).unwrap(),
+        serde_json::json!({
+            "type": "file_search",
+            "vector_store_ids": ["vs_68a1c2"],
+            "filters": { "type": "gte", "key": "year", "value": 2023 },
+            "ranking_options": { "ranker": "auto" }
+        })
+    );
+
+    // Rankers introduced after this crate was published are preserved as-is.
+    let ranking: FileSearchToolRankingOptions =
+        serde_json::from_value(serde_json::json!({ "ranker": "default-2026-01-01" })).unwrap();
+    assert_eq!(ranking.ranker, Some(FileSearchRanker::Other("default-2026-01-01".to_string())));
+    assert_eq!(serde_json::to_value(&ranking).unwrap(), serde_json::json!({ "ranker": "default-2026-01-01" }));
+}
@@ -0,0 +1,104 @@
+// tests/files_test.rs
+
+use futures_util::TryStreamExt;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use reqwest::{Method, StatusCode};
+use sh_openai_responses::client::files::{
+    create_file_from_reader, delete_file, list_files, retrieve_file, retrieve_file_content,
+    retrieve_file_content_stream,
+};
+use sh_openai_responses::client::Client;
+use sh_openai_responses::interceptor::canned_response;
+use sh_openai_responses::models::files::{FileListParams, FilePurpose};
+use sh_openai_responses::transport::{InMemoryTransport, RecordedRequest};
+use serde_json::json;
+use std::sync::Arc;
+
+const CONTENT: &str = "{\"custom_id\":\"1\"}\n{\"custom_id\":\"2\"}\n";
+
+fn file_json() -> serde_json::Value {
+    json!({
+        "id": "file-1",
+        "object": "file",
+        "bytes": CONTENT.len(),
+        "created_at": 1741476542,
+        "filename": "batch.jsonl",
+        "purpose": "batch"
+    })
+}
+
+/// Answers like the Files API, keyed by method and path.
+fn files_api(request: &RecordedRequest) -> reqwest::Response {
+    let body = match (request.method.clone(), request.url.path()) {
+        (Method::POST, "/v1/files") | (Method::GET, "/v1/files/file-1") => file_json().to_string(),
+        (Method::GET, "/v1/files") => json!({ "object": "list", "data": [file_json()], "has_more": false }).to_string(),
+        (Method::DELETE, "/v1/files/file-1") => json!({ "id": "file-1", "object": "file", "deleted": true }).to_string(),
+        (Method::GET, "/v1/files/file-1/content") => CONTENT.to_string(),
+        (method, path) => panic!("unexpected request {method} {path}"),
+    };
+    canned_response(StatusCode::OK, HeaderMap::new(), body)
+}
+
+fn client(transport: Arc<InMemoryTransport>) -> Client {
+    Client::new_with_base_url("sk-test".to_string(), "https://api.example.test/v1".to_string())
+        .with_transport(transport)
+}
+
+#[tokio::test]
+async fn test_streamed_upload_sends_multipart_parts() {
+    let transport = Arc::new(InMemoryTransport::new(files_api));
+    let client = client(transport.clone());
+
+    let reader = std::io::Cursor::new(CONTENT.as_bytes().to_vec());
+    let file = create_file_from_reader(&client, reader, Some(CONTENT.len() as u64), "batch.jsonl", FilePurpose::Batch)
+        .await
+        .unwrap();
+    assert_eq!(file.id, "file-1");
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.url.path(), "/v1/files");
+    let content_type = request.headers[CONTENT_TYPE].to_str().unwrap();
+    let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap();
+    let body = String::from_utf8(request.body.to_vec()).unwrap();
+    let parts: Vec<_> = body.split(&format!("--{boundary}")).map(str::trim).filter(|part| !part.is_empty() && *part != "--").collect();
+    assert_eq!(parts.len(), 2);
+    assert!(parts[0].starts_with("Content-Disposition: form-data; name=\"purpose\""));
+    assert!(parts[0].ends_with("\r\n\r\nbatch"));
+    assert!(parts[1].starts_with("Content-Disposition: form-data; name=\"file\"; filename=\"batch.jsonl\""));
+    assert!(parts[1].ends_with(CONTENT.trim()));
+}
+
+#[tokio::test]
+async fn test_list_retrieve_download_and_delete() {
+    let transport = Arc::new(InMemoryTransport::new(files_api));
+    let client = client(transport.clone());
+
+    let params = FileListParams { limit: Some(10), purpose: Some(FilePurpose::Batch), ..Default::default() };
+    let page = list_files(&client, Some(params)).await.unwrap();
+    assert_eq!(page.data[0].filename, "batch.jsonl");
+    assert_eq!(retrieve_file(&client, "file-1").await.unwrap().bytes, CONTENT.len() as u64);
+    assert_eq!(retrieve_file_content(&client, "file-1").await.unwrap(), CONTENT.as_bytes());
+    let chunks: Vec<_> = retrieve_file_content_stream(&client, "file-1").await.unwrap().try_collect().await.unwrap();
+    assert_eq!(chunks.concat(), CONTENT.as_bytes());
+    assert!(delete_file(&client, "file-1").await.unwrap().deleted);
+
+    let requests: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| match request.url.query() {
+            Some(query) => format!("{} {}?{query}", request.method, request.url.path()),
+            None => format!("{} {}", request.method, request.url.path()),
+        })
+        .collect();
+    assert_eq!(
+        requests,
+        [
+            "GET /v1/files?limit=10&purpose=batch",
+            "GET /v1/files/file-1",
+            "GET /v1/files/file-1/content",
+            "GET /v1/files/file-1/content",
+            "DELETE /v1/files/file-1",
+        ]
+    );
+}
@@ -0,0 +1,53 @@
+// tests/filter_test.rs
+
+use sh_openai_responses::models::filters::{attr, ComparisonOperator, CompoundOperator, Filter, FilterValue};
+
+#[test]
+fn test_filter_dsl_serializes_to_api_json() {
+    let filter = attr("dept")
+        .eq("cardiology")
+        .and(attr("year").gte(2023))
+        .and(attr("status").is_in(["final", "amended"]))
+        .and(attr("draft").ne(true).or(attr("score").lt(0.5)));
+
+    assert_eq!(
+        serde_json::to_value(&filter).unwrap(),
+        serde_json::json!({
+            "type": "and",
+            "filters": [
+                { "type": "eq", "key": "dept", "value": "cardiology" },
+                { "type": "gte", "key": "year", "value": 2023 },
+                { "type": "in", "key": "status", "value": ["final", "amended"] },
+                {
+                    "type": "or",
+                    "filters": [
+                        { "type": "ne", "key": "draft", "value": true },
+                        { "type": "lt", "key": "score", "value": 0.5 }
+                    ]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_filter_deserializes_nested_filters() {
+    let filter: Filter = serde_json::from_value(serde_json::json!({
+        "type": "or",
+        "filters": [
+            { "type": "nin", "key": "region", "value": ["eu", "apac"] },
+            { "type": "lte", "key": "year", "value": 2020 }
+        ]
+    }))
+    .unwrap();
+
+    let Filter::Compound(compound) = &filter else { panic!("expected a compound filter") };
+    assert_eq!(compound.type_field, CompoundOperator::Or);
+    let Filter::Comparison(comparison) = &compound.filters[0] else { panic!("expected a comparison") };
+    assert_eq!(comparison.type_field, ComparisonOperator::Nin);
+    assert_eq!(comparison.value, FilterValue::from_iter(["eu", "apac"]));
+    assert_eq!(filter, Filter::any([attr("region").not_in(["eu", "apac"]), attr("year").lte(2020)]));
+
+    // An object that is neither kind of filter is rejected rather than silently accepted.
+    assert!(serde_json::from_value::<Filter>(serde_json::json!({ "foo": "bar" })).is_err());
+}
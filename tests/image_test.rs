@@ -0,0 +1,73 @@
+// tests/image_test.rs
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use sh_openai_responses::client::images::save_images;
+use sh_openai_responses::models::images::{ImageUpload, ImagesResponse};
+use sh_openai_responses::models::responses::{
+    Response, ResponseInputItem, ResponseOutputItem, ToolImageGenerationInputImageMask,
+};
+use sh_openai_responses::reasoning::output_to_input;
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n-png";
+const JPEG: &[u8] = b"\xFF\xD8\xFF\xE0-jpeg";
+
+#[test]
+fn test_saves_images_from_images_api_and_responses() {
+    let dir = std::env::temp_dir().join(format!("image_test_{}", std::process::id()));
+
+    let images: ImagesResponse = serde_json::from_value(serde_json::json!({
+        "created": 1713833628,
+        "data": [{ "b64_json": STANDARD.encode(PNG) }, { "b64_json": STANDARD.encode(JPEG) }],
+        "usage": { "input_tokens": 50, "output_tokens": 4160, "total_tokens": 4210 }
+    }))
+    .unwrap();
+    let paths = save_images(&images, &dir, "generated").unwrap();
+    assert_eq!(paths, vec![dir.join("generated-0.png"), dir.join("generated-1.jpeg")]);
+    assert_eq!(std::fs::read(&paths[1]).unwrap(), JPEG);
+
+    let response: Response = serde_json::from_value(serde_json::json!({
+        "id": "resp_1",
+        "object": "response",
+        "created_at": 1741476542,
+        "model": "gpt-4.1",
+        "output": [
+            { "type": "image_generation_call", "id": "ig_1", "status": "completed", "result": STANDARD.encode(PNG) }
+        ],
+        "parallel_tool_calls": true,
+        "tool_choice": "auto",
+        "tools": []
+    }))
+    .unwrap();
+    let paths = save_images(&response, &dir, "tool").unwrap();
+    assert_eq!(paths, vec![dir.join("tool-0.png")]);
+    assert_eq!(std::fs::read(&paths[0]).unwrap(), PNG);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_converts_tool_mask_into_upload() {
+    let mask = ToolImageGenerationInputImageMask {
+        file_id: None,
+        image_url: Some(format!("data:image/png;base64,{}", STANDARD.encode(PNG))),
+    };
+    let upload = ImageUpload::try_from(&mask).unwrap();
+    assert_eq!(upload.data, PNG);
+    assert_eq!(upload.filename, "mask.png");
+
+    let by_file = ToolImageGenerationInputImageMask { file_id: Some("file-1".to_string()), image_url: None };
+    assert!(ImageUpload::try_from(&by_file).is_err());
+}
+
+#[test]
+fn test_output_items_dispatch_on_type_and_keep_unknown_items() {
+    let image_call = serde_json::json!({ "type": "image_generation_call", "id": "ig_1", "status": "completed", "result": null });
+    let unknown = serde_json::json!({ "type": "shell_call", "id": "sh_1", "status": "completed", "action": { "commands": ["ls"] } });
+    let items: Vec<ResponseOutputItem> = serde_json::from_value(serde_json::json!([image_call, unknown])).unwrap();
+
+    assert!(matches!(&items[0], ResponseOutputItem::ImageGenerationCall(call) if call.id == "ig_1"));
+    assert_eq!(items[1], ResponseOutputItem::Other(unknown.clone()));
+    assert_eq!(serde_json::to_value(&items[1]).unwrap(), unknown);
+    assert_eq!(output_to_input(&items)[1], ResponseInputItem::Other(unknown));
+}
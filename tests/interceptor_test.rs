@@ -0,0 +1,79 @@
+// tests/interceptor_test.rs
+
+mod common;
+
+use common::{answering, offline};
+use futures_util::future::BoxFuture;
+use futures_util::StreamExt;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{Request, Response, StatusCode};
+use sh_openai_responses::client::model::retrieve_model;
+use sh_openai_responses::client::{create_response_stream, Result};
+use sh_openai_responses::interceptor::{canned_response, Interceptor, RequestInfo};
+use sh_openai_responses::models::responses::{ResponseCreateParams, ResponseStreamEvent};
+use std::sync::{Arc, Mutex};
+
+/// Adds a correlation header and records the status of every response.
+#[derive(Default)]
+struct Correlate {
+    statuses: Mutex<Vec<(String, StatusCode)>>,
+}
+
+impl Interceptor for Correlate {
+    fn before_request<'a>(&'a self, request: &'a mut Request) -> BoxFuture<'a, Result<Option<Response>>> {
+        request.headers_mut().insert("x-correlation-id", HeaderValue::from_static("corr-7"));
+        Box::pin(async { Ok(None) })
+    }
+
+    fn after_response<'a>(
+        &'a self,
+        request: &'a RequestInfo,
+        response: Response,
+    ) -> BoxFuture<'a, Result<Response>> {
+        self.statuses.lock().unwrap().push((request.url.path().to_string(), response.status()));
+        Box::pin(async { Ok(response) })
+    }
+}
+
+/// Answers every request with a canned event stream.
+struct Replay;
+
+impl Interceptor for Replay {
+    fn before_request<'a>(&'a self, _request: &'a mut Request) -> BoxFuture<'a, Result<Option<Response>>> {
+        let body = concat!(
+            "data: {\"type\":\"response.output_text.delta\",\"content_index\":0,\"delta\":\"Hi\",",
+            "\"item_id\":\"msg_1\",\"output_index\":0,\"sequence_number\":1}\n\n",
+            "data: [DONE]\n\n",
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+        Box::pin(async move { Ok(Some(canned_response(StatusCode::OK, headers, body))) })
+    }
+}
+
+#[tokio::test]
+async fn test_interceptor_modifies_request_and_observes_response() {
+    let body = serde_json::json!({ "id": "gpt-4.1", "object": "model", "created": 1744316542, "owned_by": "system" });
+    let transport = answering(body);
+    let correlate = Arc::new(Correlate::default());
+    let client = common::client(transport.clone()).with_interceptor(correlate.clone());
+
+    let model = retrieve_model(&client, "gpt-4.1").await.unwrap();
+    assert_eq!(model.id, "gpt-4.1");
+    assert_eq!(transport.requests()[0].headers["x-correlation-id"], "corr-7");
+    assert_eq!(*correlate.statuses.lock().unwrap(), vec![("/v1/models/gpt-4.1".to_string(), StatusCode::OK)]);
+}
+
+#[tokio::test]
+async fn test_interceptor_short_circuits_streams() {
+    let correlate = Arc::new(Correlate::default());
+    let client = common::client(offline())
+        .with_interceptor(correlate.clone())
+        .with_interceptor(Arc::new(Replay));
+
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Hello").build();
+    let events: Vec<_> = create_response_stream(&client, params).await.unwrap().collect().await;
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], Ok(ResponseStreamEvent::ResponseTextDelta(delta)) if delta.delta == "Hi"));
+    assert_eq!(*correlate.statuses.lock().unwrap(), vec![("/v1/responses".to_string(), StatusCode::OK)]);
+}
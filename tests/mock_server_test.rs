@@ -0,0 +1,78 @@
+// tests/mock_server_test.rs
+
+#![cfg(feature = "testing")]
+
+mod common;
+
+use common::{response_json, text_delta_json as text_delta};
+use futures_util::StreamExt;
+use reqwest::{Method, StatusCode};
+use sh_openai_responses::client::{create_response, create_response_stream, retrieve_response, Error};
+use sh_openai_responses::models::responses::{ResponseCreateParams, ResponseStreamEvent};
+use sh_openai_responses::testing::{MockReply, MockRoute, MockServer};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_serves_scripted_replies_and_records_requests() {
+    let server = MockServer::start().await;
+    server.enqueue(MockRoute::CreateResponse, MockReply::sse([text_delta("Rest", 1), text_delta(" well", 2)]));
+    server.enqueue(MockRoute::RetrieveResponse, MockReply::json(&response_json("completed")));
+    let client = server.client();
+
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Any advice?").build();
+    let events: Vec<_> = create_response_stream(&client, params).await.unwrap().collect().await;
+    let deltas: Vec<_> = events
+        .into_iter()
+        .map(|event| match event.unwrap() {
+            ResponseStreamEvent::ResponseTextDelta(delta) => delta.delta,
+            other => panic!("unexpected event {other:?}"),
+        })
+        .collect();
+    assert_eq!(deltas, ["Rest", " well"]);
+
+    let response = retrieve_response(&client, "resp_1", None).await.unwrap();
+    assert_eq!(response.id, "resp_1");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(requests[0].url.path(), "/responses");
+    assert_eq!(requests[0].headers["authorization"], "Bearer sk-mock");
+    assert_eq!(requests[0].json().unwrap()["stream"], true);
+    assert_eq!(requests[1].url.path(), "/responses/resp_1");
+}
+
+#[tokio::test]
+async fn test_injects_errors_latency_and_disconnects() {
+    let server = MockServer::start().await;
+    server.enqueue(MockRoute::CreateResponse, MockReply::error(503, "server_error", "Overloaded"));
+    server.enqueue(
+        MockRoute::CreateResponse,
+        MockReply::sse([text_delta("Rest", 1), text_delta(" well", 2)])
+            .with_delay(Duration::from_millis(50))
+            .disconnect_after(1),
+    );
+    let client = server.client();
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Any advice?").build();
+
+    match create_response(&client, params.clone()).await {
+        Err(Error::ApiError { status, error }) => {
+            assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(error.message, "Overloaded");
+        }
+        other => panic!("expected ApiError, got {other:?}"),
+    }
+
+    let started = Instant::now();
+    let events: Vec<_> = create_response_stream(&client, params.clone()).await.unwrap().collect().await;
+    assert!(started.elapsed() >= Duration::from_millis(50));
+    assert_eq!(events.len(), 2);
+    assert!(events[0].is_ok());
+    assert!(matches!(events[1], Err(Error::Reqwest(_))));
+
+    // Nothing left to reply with.
+    match create_response(&client, params).await {
+        Err(Error::UnexpectedResponse(message)) => assert!(message.contains("404"), "{message}"),
+        other => panic!("expected a 404, got {other:?}"),
+    }
+}
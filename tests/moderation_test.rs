@@ -0,0 +1,98 @@
+// tests/moderation_test.rs
+
+use sh_openai_responses::models::moderations::{
+    ModerationCreateResponse, ModerationInput, ModerationInputType, ModerationMultiModalInput,
+};
+use sh_openai_responses::models::responses::{
+    EasyInputMessage, EasyInputMessageContent, MessageRole, ResponseCreateParams, ResponseInputItem,
+    ResponseInputParam,
+};
+use sh_openai_responses::moderation::ModerationGuard;
+
+fn moderation(flagged: bool, self_harm: bool, self_harm_score: f64) -> ModerationCreateResponse {
+    serde_json::from_value(serde_json::json!({
+        "id": "modr-970d409e",
+        "model": "omni-moderation-latest",
+        "results": [{
+            "flagged": flagged,
+            "categories": {
+                "harassment": false, "harassment/threatening": false,
+                "hate": false, "hate/threatening": false,
+                "illicit": null, "illicit/violent": null,
+                "self-harm": self_harm, "self-harm/instructions": false, "self-harm/intent": self_harm,
+                "sexual": false, "sexual/minors": false,
+                "violence": false, "violence/graphic": false
+            },
+            "category_scores": {
+                "harassment": 0.0001, "harassment/threatening": 0.0001,
+                "hate": 0.00001, "hate/threatening": 0.000001,
+                "illicit": 0.00002, "illicit/violent": 0.00001,
+                "self-harm": self_harm_score, "self-harm/instructions": 0.0002, "self-harm/intent": self_harm_score,
+                "sexual": 0.00003, "sexual/minors": 0.000004,
+                "violence": 0.0004, "violence/graphic": 0.00001
+            },
+            "category_applied_input_types": { "self-harm": ["text"], "violence": ["text", "image"] }
+        }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_guard_screens_only_user_input() {
+    let message = |role, text: &str| {
+        ResponseInputItem::EasyMessage(EasyInputMessage {
+            content: EasyInputMessageContent::String(text.to_string()),
+            role,
+            type_field: None,
+        })
+    };
+    let params = ResponseCreateParams {
+        model: Some("gpt-4.1".to_string()),
+        input: Some(ResponseInputParam::Items(vec![
+            message(MessageRole::Developer, "You are a triage assistant."),
+            message(MessageRole::User, "My chest hurts when I climb stairs."),
+        ])),
+        ..Default::default()
+    };
+
+    let guard = ModerationGuard::new().with_model("omni-moderation-latest");
+    let request = guard.params_for(&params).unwrap();
+    assert_eq!(
+        request.input,
+        ModerationInput::MultiModal(vec![ModerationMultiModalInput::Text {
+            text: "My chest hurts when I climb stairs.".to_string()
+        }])
+    );
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "input": [{ "type": "text", "text": "My chest hurts when I climb stairs." }],
+            "model": "omni-moderation-latest"
+        })
+    );
+
+    let legacy = ModerationGuard::new().with_model("text-moderation-latest").params_for(&params).unwrap();
+    assert_eq!(legacy.input, ModerationInput::StringArray(vec!["My chest hurts when I climb stairs.".to_string()]));
+
+    let no_user_input = ResponseCreateParams { input: None, ..params };
+    assert!(guard.params_for(&no_user_input).is_none());
+}
+
+#[test]
+fn test_guard_reports_flagged_categories_and_thresholds() {
+    let flagged = moderation(true, true, 0.91);
+    let result = &flagged.results[0];
+    assert_eq!(result.categories.flagged(), vec!["self-harm", "self-harm/intent"]);
+    assert!(!result.categories.illicit);
+    assert_eq!(
+        result.category_applied_input_types.as_ref().unwrap()["violence"],
+        vec![ModerationInputType::Text, ModerationInputType::Image]
+    );
+
+    let guard = ModerationGuard::new();
+    assert_eq!(guard.violations(&flagged), vec!["self-harm", "self-harm/intent"]);
+
+    let borderline = moderation(false, false, 0.4);
+    assert!(guard.violations(&borderline).is_empty());
+    assert_eq!(guard.with_score_threshold(0.3).violations(&borderline), vec!["score_threshold"]);
+}
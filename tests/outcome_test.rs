@@ -0,0 +1,72 @@
+// tests/outcome_test.rs
+
+mod common;
+
+use common::{answering, client, response_json};
+use sh_openai_responses::client::{create_response, Error};
+use sh_openai_responses::models::outcome::ResponseOutcome;
+use sh_openai_responses::models::responses::{
+    IncompleteDetailsReason, Response, ResponseCreateParams, ResponseErrorCode, ResponseStreamEvent,
+};
+use serde_json::json;
+
+#[test]
+fn test_outcome_from_response_and_final_event() {
+    let completed: Response = serde_json::from_value(response_json("completed")).unwrap();
+    assert_eq!(completed.outcome(), ResponseOutcome::Completed);
+
+    let mut incomplete = response_json("incomplete");
+    incomplete["incomplete_details"] = json!({ "reason": "max_output_tokens" });
+    let incomplete: Response = serde_json::from_value(incomplete).unwrap();
+    assert_eq!(
+        incomplete.outcome(),
+        ResponseOutcome::Incomplete { reason: Some(IncompleteDetailsReason::MaxOutputTokens) }
+    );
+
+    let queued: Response = serde_json::from_value(response_json("queued")).unwrap();
+    assert_eq!(queued.outcome(), ResponseOutcome::Pending);
+    assert!(!queued.outcome().is_terminal());
+
+    let mut failed = response_json("failed");
+    failed["error"] = json!({ "code": "rate_limit_exceeded", "message": "Slow down" });
+    let event: ResponseStreamEvent =
+        serde_json::from_value(json!({ "type": "response.failed", "sequence_number": 3, "response": failed })).unwrap();
+    match ResponseOutcome::from_event(&event) {
+        Some(ResponseOutcome::Failed(error)) => {
+            assert_eq!(error.code, ResponseErrorCode::RateLimitExceeded);
+            assert_eq!(error.message, "Slow down");
+        }
+        other => panic!("expected a failed outcome, got {other:?}"),
+    }
+
+    let event: ResponseStreamEvent = serde_json::from_value(json!({
+        "type": "response.output_text.delta",
+        "sequence_number": 1,
+        "item_id": "msg_1",
+        "output_index": 0,
+        "content_index": 0,
+        "delta": "Hi"
+    }))
+    .unwrap();
+    assert_eq!(ResponseOutcome::from_event(&event), None);
+}
+
+#[tokio::test]
+async fn test_outcome_errors_turn_incomplete_responses_into_errors() {
+    let mut body = response_json("incomplete");
+    body["incomplete_details"] = json!({ "reason": "content_filter" });
+    let client = client(answering(body));
+    let params = || ResponseCreateParams::builder("gpt-4.1").user_text("Hello").build();
+
+    let response = create_response(&client, params()).await.unwrap();
+    assert!(!response.outcome().is_completed());
+
+    let client = client.with_outcome_errors(true);
+    match create_response(&client, params()).await {
+        Err(Error::ResponseUnsuccessful { outcome, response }) => {
+            assert_eq!(outcome, ResponseOutcome::Incomplete { reason: Some(IncompleteDetailsReason::ContentFilter) });
+            assert_eq!(response.id, "resp_1");
+        }
+        other => panic!("expected ResponseUnsuccessful, got {other:?}"),
+    }
+}
@@ -0,0 +1,66 @@
+// tests/rate_limit_test.rs
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use sh_openai_responses::rate_limit::{parse_reset, retry_backoff, RateLimiter, RateLimits, MAX_RETRY_BACKOFF};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_parse_reset_durations() {
+    assert_eq!(parse_reset("1s"), Some(Duration::from_secs(1)));
+    assert_eq!(parse_reset("20ms"), Some(Duration::from_millis(20)));
+    assert_eq!(parse_reset("6m0s"), Some(Duration::from_secs(360)));
+    assert_eq!(parse_reset("1h2m3.5s"), Some(Duration::from_secs_f64(3723.5)));
+    assert_eq!(parse_reset(""), None);
+    assert_eq!(parse_reset("soon"), None);
+}
+
+#[test]
+fn test_retry_backoff_is_capped() {
+    assert_eq!(retry_backoff(1), Duration::from_secs(2));
+    assert_eq!(retry_backoff(3), Duration::from_secs(8));
+    assert_eq!(retry_backoff(20), MAX_RETRY_BACKOFF);
+    assert_eq!(retry_backoff(200), MAX_RETRY_BACKOFF);
+}
+
+#[tokio::test]
+async fn test_headers_calibrate_and_block_until_reset() {
+    let limiter = RateLimiter::new(RateLimits::new());
+
+    // Without limits nothing is throttled.
+    let start = Instant::now();
+    for _ in 0..100 {
+        limiter.acquire("gpt-4.1", 1_000).await;
+    }
+    assert!(start.elapsed() < Duration::from_millis(50));
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-ratelimit-limit-requests", HeaderValue::from_static("500"));
+    headers.insert("x-ratelimit-remaining-requests", HeaderValue::from_static("0"));
+    headers.insert("x-ratelimit-reset-requests", HeaderValue::from_static("300ms"));
+    headers.insert("x-ratelimit-limit-tokens", HeaderValue::from_static("30000"));
+    headers.insert("x-ratelimit-remaining-tokens", HeaderValue::from_static("29000"));
+    headers.insert("x-ratelimit-reset-tokens", HeaderValue::from_static("2s"));
+    limiter.observe("gpt-4.1", &headers);
+
+    let start = Instant::now();
+    limiter.acquire("gpt-4.1", 1_000).await;
+    assert!(start.elapsed() >= Duration::from_millis(250));
+
+    // Other models are tracked separately.
+    let start = Instant::now();
+    limiter.acquire("gpt-4.1-mini", 1_000).await;
+    assert!(start.elapsed() < Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn test_token_bucket_queues_instead_of_failing() {
+    // 6000 tokens/minute refills at 100 tokens per second.
+    let limiter = RateLimiter::new(RateLimits::new().tokens_per_minute(6_000.0));
+
+    let start = Instant::now();
+    limiter.acquire("gpt-4.1", 6_000).await;
+    limiter.acquire("gpt-4.1", 30).await;
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(250), "waited {elapsed:?}");
+    assert!(elapsed < Duration::from_secs(2), "waited {elapsed:?}");
+}
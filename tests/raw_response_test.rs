@@ -0,0 +1,57 @@
+// tests/raw_response_test.rs
+
+mod common;
+
+use common::client;
+use futures_util::StreamExt;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+use sh_openai_responses::client::model::retrieve_model;
+use sh_openai_responses::client::raw::with_raw_response;
+use sh_openai_responses::client::retrieve_response_stream;
+use sh_openai_responses::interceptor::canned_response;
+use sh_openai_responses::models::responses::ResponseRetrieveParams;
+use sh_openai_responses::transport::InMemoryTransport;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A transport that answers every request with `body` and the given headers.
+fn serving(
+    content_type: &'static str,
+    headers: &'static [(&'static str, &'static str)],
+    body: &'static str,
+) -> Arc<InMemoryTransport> {
+    Arc::new(InMemoryTransport::new(move |_| {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        for &(name, value) in headers {
+            header_map.insert(name, HeaderValue::from_static(value));
+        }
+        canned_response(StatusCode::OK, header_map, body)
+    }))
+}
+
+#[tokio::test]
+async fn test_returns_typed_data_with_raw_response() {
+    let body = r#"{"id":"gpt-4.1","object":"model","created":1744316542,"owned_by":"system"}"#;
+    let headers = &[("x-request-id", "req_abc"), ("openai-processing-ms", "42")];
+    let client = client(serving("application/json", headers, body));
+
+    let model = with_raw_response(retrieve_model(&client, "gpt-4.1")).await.unwrap();
+    assert_eq!(model.data.id, "gpt-4.1");
+    assert_eq!(model.raw.status, 200);
+    assert_eq!(model.raw.request_id(), Some("req_abc"));
+    assert_eq!(model.raw.processing_time(), Some(Duration::from_millis(42)));
+    assert_eq!(model.raw.body, body.as_bytes());
+}
+
+#[tokio::test]
+async fn test_streams_expose_headers_without_body() {
+    let client = client(serving("text/event-stream", &[("x-request-id", "req_stream")], "data: [DONE]\n\n"));
+
+    let params = ResponseRetrieveParams::default();
+    let stream = with_raw_response(retrieve_response_stream(&client, "resp_1", params)).await.unwrap();
+    assert_eq!(stream.raw.request_id(), Some("req_stream"));
+    assert!(stream.raw.body.is_empty());
+    assert!(Box::pin(stream.data).next().await.is_none());
+}
@@ -0,0 +1,88 @@
+// tests/reasoning_test.rs
+
+use sh_openai_responses::models::responses::{
+    Reasoning, ReasoningEffort, ReasoningSummary, Response, ResponseCreateParams, ResponseIncludable,
+    ResponseInputParam,
+};
+use sh_openai_responses::reasoning::{append_output, encrypted_reasoning_items, request_encrypted_reasoning};
+
+fn reasoning_response() -> Response {
+    serde_json::from_value(serde_json::json!({
+        "id": "resp_1",
+        "object": "response",
+        "created_at": 1741476542,
+        "model": "o4-mini-2025-04-16",
+        "reasoning": { "effort": "high", "summary": "detailed" },
+        "output": [
+            {
+                "type": "reasoning",
+                "id": "rs_1",
+                "summary": [{ "type": "summary_text", "text": "Checked dosage limits." }],
+                "encrypted_content": "gAAAAABo..."
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "role": "assistant",
+                "status": "completed",
+                "content": [{ "type": "output_text", "text": "The dose is within limits.", "annotations": [] }]
+            }
+        ],
+        "parallel_tool_calls": true,
+        "tool_choice": "auto",
+        "tools": []
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_reasoning_config_is_typed() {
+    let response = reasoning_response();
+    assert_eq!(
+        response.reasoning,
+        Some(Reasoning {
+            effort: Some(ReasoningEffort::High),
+            summary: Some(ReasoningSummary::Detailed),
+            generate_summary: None,
+        })
+    );
+
+    let reasoning = Reasoning { effort: Some(ReasoningEffort::Minimal), ..Default::default() };
+    assert_eq!(serde_json::to_value(&reasoning).unwrap(), serde_json::json!({ "effort": "minimal" }));
+}
+
+#[test]
+fn test_unknown_reasoning_levels_do_not_fail_the_response() {
+    let mut json = serde_json::to_value(reasoning_response()).unwrap();
+    json["reasoning"] = serde_json::json!({ "effort": "none", "summary": "verbose" });
+    let response: Response = serde_json::from_value(json).unwrap();
+
+    let reasoning = response.reasoning.unwrap();
+    assert_eq!(reasoning.effort, Some(ReasoningEffort::Other("none".to_string())));
+    assert_eq!(reasoning.summary, Some(ReasoningSummary::Other("verbose".to_string())));
+    assert_eq!(serde_json::to_value(&reasoning).unwrap(), serde_json::json!({ "effort": "none", "summary": "verbose" }));
+}
+
+#[test]
+fn test_threads_encrypted_reasoning_into_next_turn() {
+    let mut params = ResponseCreateParams {
+        model: Some("o4-mini".to_string()),
+        input: Some(ResponseInputParam::String("Is 40mg too high?".to_string())),
+        ..Default::default()
+    };
+    request_encrypted_reasoning(&mut params);
+    request_encrypted_reasoning(&mut params);
+    assert_eq!(params.store, Some(false));
+    assert_eq!(params.include, Some(vec![ResponseIncludable::ReasoningEncryptedContent]));
+
+    let response = reasoning_response();
+    assert_eq!(encrypted_reasoning_items(&response).len(), 1);
+
+    append_output(&mut params, &response);
+    let input = serde_json::to_value(&params.input).unwrap();
+    assert_eq!(input[0], serde_json::json!({ "role": "user", "content": "Is 40mg too high?" }));
+    assert_eq!(input[1]["type"], "reasoning");
+    assert_eq!(input[1]["encrypted_content"], "gAAAAABo...");
+    assert_eq!(input[2]["type"], "message");
+    assert_eq!(input[2]["role"], "assistant");
+}
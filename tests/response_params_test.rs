@@ -0,0 +1,59 @@
+// tests/response_params_test.rs
+
+use sh_openai_responses::models::responses::{
+    EasyInputMessage, EasyInputMessageContent, MessageRole, ResponseConversationParam, ResponseCreateParams,
+    ResponseInputItem, ResponseInstructions, ResponseStreamOptions, ResponseTextConfig, Verbosity,
+};
+
+#[test]
+fn test_serializes_full_create_params_surface() {
+    let mut params = ResponseCreateParams {
+        model: Some("gpt-5".to_string()),
+        instructions: Some(ResponseInstructions::Items(vec![ResponseInputItem::EasyMessage(EasyInputMessage {
+            content: EasyInputMessageContent::String("Answer as a triage nurse.".to_string()),
+            role: MessageRole::Developer,
+            type_field: None,
+        })])),
+        conversation: Some(ResponseConversationParam::Id("conv_123".to_string())),
+        prompt_cache_key: Some("triage-v2".to_string()),
+        safety_identifier: Some("user-hash-42".to_string()),
+        max_tool_calls: Some(4),
+        top_logprobs: Some(3),
+        stream_options: Some(ResponseStreamOptions { include_obfuscation: Some(false) }),
+        text: Some(ResponseTextConfig { verbosity: Some(Verbosity::Low), ..Default::default() }),
+        ..Default::default()
+    };
+    params.extra.insert("context_management".to_string(), serde_json::json!({ "mode": "auto" }));
+
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "model": "gpt-5",
+            "instructions": [{ "role": "developer", "content": "Answer as a triage nurse." }],
+            "conversation": "conv_123",
+            "prompt_cache_key": "triage-v2",
+            "safety_identifier": "user-hash-42",
+            "max_tool_calls": 4,
+            "top_logprobs": 3,
+            "stream_options": { "include_obfuscation": false },
+            "text": { "verbosity": "low" },
+            "context_management": { "mode": "auto" }
+        })
+    );
+}
+
+#[test]
+fn test_unknown_fields_land_in_extra() {
+    let params: ResponseCreateParams = serde_json::from_value(serde_json::json!({
+        "model": "gpt-4.1",
+        "instructions": "Be brief.",
+        "conversation": { "id": "conv_123" },
+        "future_option": [1, 2, 3]
+    }))
+    .unwrap();
+
+    assert_eq!(params.instructions, Some(ResponseInstructions::String("Be brief.".to_string())));
+    assert_eq!(params.conversation, Some(ResponseConversationParam::Object { id: "conv_123".to_string() }));
+    assert_eq!(params.extra.len(), 1);
+    assert_eq!(params.extra["future_option"], serde_json::json!([1, 2, 3]));
+}
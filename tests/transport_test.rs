@@ -0,0 +1,49 @@
+// tests/transport_test.rs
+
+mod common;
+
+use common::{answering, client, response_json};
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use reqwest::{Method, StatusCode};
+use sh_openai_responses::client::{create_response, Error};
+use sh_openai_responses::interceptor::canned_response;
+use sh_openai_responses::models::responses::ResponseCreateParams;
+use sh_openai_responses::transport::InMemoryTransport;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_in_memory_transport_answers_requests() {
+    let transport = answering(response_json("completed"));
+    let client = client(transport.clone());
+
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Hello").build();
+    let response = create_response(&client, params).await.unwrap();
+    assert_eq!(response.id, "resp_1");
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(requests[0].url.as_str(), "https://api.example.test/v1/responses");
+    assert_eq!(requests[0].headers[AUTHORIZATION], "Bearer sk-test");
+    let body = requests[0].json().unwrap();
+    assert_eq!(body["model"], "gpt-4.1");
+    assert_eq!(body["stream"], false);
+}
+
+#[tokio::test]
+async fn test_in_memory_transport_surfaces_api_errors() {
+    let transport = Arc::new(InMemoryTransport::new(|_| {
+        let body = r#"{"code":"server_error","message":"The server had an error."}"#;
+        canned_response(StatusCode::INTERNAL_SERVER_ERROR, HeaderMap::new(), body)
+    }));
+    let client = client(transport);
+
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Hello").build();
+    match create_response(&client, params).await {
+        Err(Error::ApiError { status, error }) => {
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(error.message, "The server had an error.");
+        }
+        other => panic!("expected ApiError, got {other:?}"),
+    }
+}
@@ -0,0 +1,128 @@
+// tests/vcr_test.rs
+
+mod common;
+
+use common::{answering, client, message_response_json, offline, text_delta_json};
+use futures_util::StreamExt;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{Body, StatusCode};
+use sh_openai_responses::client::{create_response, create_response_stream, Error};
+use sh_openai_responses::interceptor::canned_response;
+use sh_openai_responses::models::responses::{Response, ResponseCreateParams, ResponseStreamEvent};
+use sh_openai_responses::transport::vcr::{Cassette, Redactor, VcrMode, VcrTransport};
+use sh_openai_responses::transport::InMemoryTransport;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+fn cassette_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("sh-openai-vcr-{}-{name}", std::process::id()))
+}
+
+fn message_text(response: &Response) -> String {
+    let output = serde_json::to_value(&response.output).unwrap();
+    output[0]["content"][0]["text"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_records_redacted_interactions_and_replays_them() {
+    let path = cassette_path("redaction.json");
+    let upstream = answering(message_response_json("Jane Doe should rest."));
+    let redactor = Redactor::new().redact_field("mrn").redact_text("Jane Doe");
+    let params = ResponseCreateParams::builder("gpt-4.1")
+        .user_text("Jane Doe has a fever. Advice?")
+        .metadata("mrn", "12345")
+        .build();
+
+    let recorder = VcrTransport::new(&path, VcrMode::Record, upstream).unwrap().with_redactor(redactor.clone());
+    let recorded = create_response(&client(Arc::new(recorder)), params.clone()).await.unwrap();
+    assert_eq!(message_text(&recorded), "Jane Doe should rest.");
+
+    let cassette = std::fs::read_to_string(&path).unwrap();
+    assert!(!cassette.contains("Jane Doe") && !cassette.contains("12345") && !cassette.contains("sk-test"));
+    let interaction = &Cassette::load(&path).unwrap().interactions[0];
+    assert_eq!(interaction.request.headers["authorization"], "[REDACTED]");
+    assert_eq!(interaction.request.body["metadata"]["mrn"], "[REDACTED]");
+
+    let player = VcrTransport::new(&path, VcrMode::Auto, offline()).unwrap().with_redactor(redactor);
+    assert!(!player.is_recording());
+    let replayed = create_response(&client(Arc::new(player)), params).await.unwrap();
+    assert_eq!(replayed.id, "resp_1");
+    assert_eq!(message_text(&replayed), "[REDACTED] should rest.");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_replays_streams_event_by_event() {
+    let path = cassette_path("stream.yaml");
+    let upstream = Arc::new(InMemoryTransport::new(|_| {
+        let event = |delta: &str, n: u64| {
+            format!("event: response.output_text.delta\ndata: {}\n\n", text_delta_json(delta, n))
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+        canned_response(StatusCode::OK, headers, event("Rest", 1) + &event(" well", 2))
+    }));
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Advice?").build();
+
+    let recorder = VcrTransport::new(&path, VcrMode::Record, upstream).unwrap();
+    let recorded: Vec<_> = create_response_stream(&client(Arc::new(recorder)), params.clone()).await.unwrap().collect().await;
+    assert_eq!(recorded.len(), 2);
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions[0].response.chunks.len(), 2);
+    assert!(std::fs::read_to_string(&path).unwrap().starts_with("interactions:"));
+
+    // The same cassette round-trips through JSON.
+    let json_path = cassette_path("stream.json");
+    cassette.save(&json_path).unwrap();
+    assert_eq!(Cassette::load(&json_path).unwrap(), cassette);
+    std::fs::remove_file(&json_path).unwrap();
+
+    let player = Arc::new(VcrTransport::new(&path, VcrMode::Replay, offline()).unwrap().with_replay_timing(true));
+    let client = client(player);
+    let replayed: Vec<_> = create_response_stream(&client, params.clone()).await.unwrap().collect().await;
+    let deltas: Vec<_> = replayed
+        .into_iter()
+        .map(|event| match event.unwrap() {
+            ResponseStreamEvent::ResponseTextDelta(delta) => delta.delta,
+            other => panic!("unexpected event {other:?}"),
+        })
+        .collect();
+    assert_eq!(deltas, ["Rest", " well"]);
+
+    // Each interaction replays only once.
+    assert!(matches!(create_response_stream(&client, params).await, Err(Error::UnexpectedResponse(_))));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_recording_passes_events_through_as_they_arrive() {
+    let path = cassette_path("passthrough.json");
+    let (events, receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let receiver = Mutex::new(Some(receiver));
+    let upstream = Arc::new(InMemoryTransport::new(move |_| {
+        let receiver = receiver.lock().unwrap().take().expect("a single request");
+        let body = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            let event = receiver.recv().await?;
+            Some((Ok::<_, std::io::Error>(event), receiver))
+        });
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+        canned_response(StatusCode::OK, headers, Body::wrap_stream(body))
+    }));
+    let event = |delta: &str, n: u64| format!("data: {}\n\n", text_delta_json(delta, n));
+    let params = ResponseCreateParams::builder("gpt-4.1").user_text("Advice?").build();
+
+    let client = client(Arc::new(VcrTransport::new(&path, VcrMode::Record, upstream).unwrap()));
+    let mut stream = std::pin::pin!(create_response_stream(&client, params).await.unwrap());
+    events.send(event("Rest", 1)).unwrap();
+    // The first event arrives while the upstream stream is still open.
+    let first = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next()).await.unwrap();
+    assert!(matches!(first, Some(Ok(ResponseStreamEvent::ResponseTextDelta(_)))));
+    assert!(!path.exists());
+
+    events.send(event(" well", 2)).unwrap();
+    drop(events);
+    assert_eq!(stream.collect::<Vec<_>>().await.len(), 1);
+    assert_eq!(Cassette::load(&path).unwrap().interactions[0].response.chunks.len(), 2);
+    std::fs::remove_file(&path).unwrap();
+}
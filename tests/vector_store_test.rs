@@ -0,0 +1,68 @@
+// tests/vector_store_test.rs
+
+use sh_openai_responses::models::vector_stores::{
+    ChunkingStrategy, StaticChunkingStrategyConfig, VectorStoreFile, VectorStoreFileCreateParams,
+    VectorStoreFileStatus, VectorStoreSearchQuery, VectorStoreSearchResultsPage,
+};
+
+#[test]
+fn test_vector_store_file_chunking_round_trip() {
+    let params = VectorStoreFileCreateParams {
+        file_id: "file-abc123".to_string(),
+        chunking_strategy: Some(ChunkingStrategy::Static {
+            config: StaticChunkingStrategyConfig { chunk_overlap_tokens: 200, max_chunk_size_tokens: 800 },
+        }),
+        ..Default::default()
+    };
+    let json = serde_json::to_value(&params).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "file_id": "file-abc123",
+            "chunking_strategy": {
+                "type": "static",
+                "static": { "chunk_overlap_tokens": 200, "max_chunk_size_tokens": 800 }
+            }
+        })
+    );
+
+    let file: VectorStoreFile = serde_json::from_value(serde_json::json!({
+        "id": "file-abc123",
+        "object": "vector_store.file",
+        "created_at": 1699061776,
+        "usage_bytes": 1234,
+        "vector_store_id": "vs_abc123",
+        "status": "completed",
+        "last_error": null,
+        "attributes": { "dept": "cardiology", "year": 2024 },
+        "chunking_strategy": { "type": "other" }
+    }))
+    .unwrap();
+    assert_eq!(file.status, VectorStoreFileStatus::Completed);
+    assert_eq!(file.chunking_strategy, Some(ChunkingStrategy::Other));
+    assert_eq!(file.attributes.unwrap()["year"], 2024);
+}
+
+#[test]
+fn test_deserializes_search_results_page() {
+    let page: VectorStoreSearchResultsPage = serde_json::from_value(serde_json::json!({
+        "object": "vector_store.search_results.page",
+        "search_query": "discharge criteria",
+        "data": [{
+            "file_id": "file-abc123",
+            "filename": "protocol.pdf",
+            "score": 0.87,
+            "attributes": { "dept": "cardiology" },
+            "content": [{ "type": "text", "text": "Patients may be discharged when..." }]
+        }],
+        "has_more": false,
+        "next_page": null
+    }))
+    .unwrap();
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(page.data[0].content[0].type_field, "text");
+    assert_eq!(
+        page.search_query,
+        Some(VectorStoreSearchQuery::String("discharge criteria".to_string()))
+    );
+}